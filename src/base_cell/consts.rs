@@ -1,6 +1,7 @@
 use lazy_static::lazy_static;
 
-use super::{BaseCellData, BaseCellRotation};
+use super::{BaseCell, BaseCellData, BaseCellRotation};
+use crate::consts::{NUM_PENTAGONS, NUM_PENT_VERTS};
 use crate::coordinate::Coordinate;
 use crate::face::Face;
 
@@ -14,6 +15,23 @@ pub const NUM_BASE_CELLS: usize = 122;
 pub const NUM_ICOSA_FACES: usize = 20;
 pub const INVALID_BASE_CELL: isize = 127;
 
+/// Direction-to-face mapping for each pentagon base cell. Faces are in
+/// directional order, starting at J.
+pub const PENTAGON_DIRECTION_FACES: [(usize, [isize; NUM_PENT_VERTS]); NUM_PENTAGONS] = [
+    (4, [4, 0, 2, 1, 3]),
+    (14, [6, 11, 2, 7, 1]),
+    (24, [5, 10, 1, 6, 0]),
+    (38, [7, 12, 3, 8, 2]),
+    (49, [9, 14, 0, 5, 4]),
+    (58, [8, 13, 4, 9, 3]),
+    (63, [11, 6, 15, 10, 16]),
+    (72, [12, 7, 16, 11, 17]),
+    (83, [10, 5, 19, 14, 15]),
+    (97, [13, 8, 17, 12, 18]),
+    (107, [14, 9, 18, 13, 19]),
+    (117, [15, 19, 17, 18, 16]),
+];
+
 lazy_static! {
     /// Neighboring base cell ID in each IJK direction.
     ///
@@ -279,96 +297,96 @@ lazy_static! {
     pub static ref FACE_IJK_BASE_CELLS: [[[[BaseCellRotation; 3]; 3]; 3]; NUM_ICOSA_FACES] = [
         [// face 0
          [ // i 0
-            [BaseCellRotation{base_cell: 16, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 18, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 24, ccw_rotation_60: 0}],  // j 0
-            [BaseCellRotation{base_cell: 33, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 30, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 32, ccw_rotation_60: 3}],  // j 1
-            [BaseCellRotation{base_cell: 49, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 48, ccw_rotation_60: 3}, BaseCellRotation{base_cell: 50, ccw_rotation_60: 3}]   // j 2
+            [BaseCellRotation{base_cell: BaseCell(16), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(18), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(24), ccw_rotation_60: 0}],  // j 0
+            [BaseCellRotation{base_cell: BaseCell(33), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(30), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(32), ccw_rotation_60: 3}],  // j 1
+            [BaseCellRotation{base_cell: BaseCell(49), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(48), ccw_rotation_60: 3}, BaseCellRotation{base_cell: BaseCell(50), ccw_rotation_60: 3}]   // j 2
          ],
          [
              // i 1
-            [BaseCellRotation{base_cell: 8, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 5, ccw_rotation_60: 5}, BaseCellRotation{base_cell: 10, ccw_rotation_60: 5}],    // j 0
-            [BaseCellRotation{base_cell: 22, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 16, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 18, ccw_rotation_60: 0}],  // j 1
-            [BaseCellRotation{base_cell: 41, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 33, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 30, ccw_rotation_60: 0}]   // j 2
+            [BaseCellRotation{base_cell: BaseCell(8), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(5), ccw_rotation_60: 5}, BaseCellRotation{base_cell: BaseCell(10), ccw_rotation_60: 5}],    // j 0
+            [BaseCellRotation{base_cell: BaseCell(22), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(16), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(18), ccw_rotation_60: 0}],  // j 1
+            [BaseCellRotation{base_cell: BaseCell(41), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(33), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(30), ccw_rotation_60: 0}]   // j 2
          ],
          [
              // i 2
-            [BaseCellRotation{base_cell: 4, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 0, ccw_rotation_60: 5}, BaseCellRotation{base_cell: 2, ccw_rotation_60: 5}],    // j 0
-            [BaseCellRotation{base_cell: 15, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 8, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 5, ccw_rotation_60: 5}],   // j 1
-            [BaseCellRotation{base_cell: 31, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 22, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 16, ccw_rotation_60: 0}]  // j 2
+            [BaseCellRotation{base_cell: BaseCell(4), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(0), ccw_rotation_60: 5}, BaseCellRotation{base_cell: BaseCell(2), ccw_rotation_60: 5}],    // j 0
+            [BaseCellRotation{base_cell: BaseCell(15), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(8), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(5), ccw_rotation_60: 5}],   // j 1
+            [BaseCellRotation{base_cell: BaseCell(31), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(22), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(16), ccw_rotation_60: 0}]  // j 2
          ],
         ],
         [// face 1
          [
              // i 0
-             [BaseCellRotation{base_cell: 2, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 6, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 14, ccw_rotation_60: 0}],    // j 0
-             [BaseCellRotation{base_cell: 10, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 11, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 17, ccw_rotation_60: 3}],  // j 1
-             [BaseCellRotation{base_cell: 24, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 23, ccw_rotation_60: 3}, BaseCellRotation{base_cell: 25, ccw_rotation_60: 3}]   // j 2
+             [BaseCellRotation{base_cell: BaseCell(2), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(6), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(14), ccw_rotation_60: 0}],    // j 0
+             [BaseCellRotation{base_cell: BaseCell(10), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(11), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(17), ccw_rotation_60: 3}],  // j 1
+             [BaseCellRotation{base_cell: BaseCell(24), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(23), ccw_rotation_60: 3}, BaseCellRotation{base_cell: BaseCell(25), ccw_rotation_60: 3}]   // j 2
          ],
          [
              // i 1
-             [BaseCellRotation{base_cell: 0, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 1, ccw_rotation_60: 5}, BaseCellRotation{base_cell: 9, ccw_rotation_60: 5}],    // j 0
-             [BaseCellRotation{base_cell: 5, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 2, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 6, ccw_rotation_60: 0}],    // j 1
-             [BaseCellRotation{base_cell: 18, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 10, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 11, ccw_rotation_60: 0}]  // j 2
+             [BaseCellRotation{base_cell: BaseCell(0), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(1), ccw_rotation_60: 5}, BaseCellRotation{base_cell: BaseCell(9), ccw_rotation_60: 5}],    // j 0
+             [BaseCellRotation{base_cell: BaseCell(5), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(2), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(6), ccw_rotation_60: 0}],    // j 1
+             [BaseCellRotation{base_cell: BaseCell(18), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(10), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(11), ccw_rotation_60: 0}]  // j 2
          ],
          [
              // i 2
-             [BaseCellRotation{base_cell: 4, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 3, ccw_rotation_60: 5}, BaseCellRotation{base_cell: 7, ccw_rotation_60: 5}],  // j 0
-             [BaseCellRotation{base_cell: 8, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 0, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 1, ccw_rotation_60: 5}],  // j 1
-             [BaseCellRotation{base_cell: 16, ccw_rotation_60: 1}, BaseCellRotation{base_cell: 5, ccw_rotation_60: 0}, BaseCellRotation{base_cell: 2, ccw_rotation_60: 0}]  // j 2
+             [BaseCellRotation{base_cell: BaseCell(4), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(3), ccw_rotation_60: 5}, BaseCellRotation{base_cell: BaseCell(7), ccw_rotation_60: 5}],  // j 0
+             [BaseCellRotation{base_cell: BaseCell(8), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(0), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(1), ccw_rotation_60: 5}],  // j 1
+             [BaseCellRotation{base_cell: BaseCell(16), ccw_rotation_60: 1}, BaseCellRotation{base_cell: BaseCell(5), ccw_rotation_60: 0}, BaseCellRotation{base_cell: BaseCell(2), ccw_rotation_60: 0}]  // j 2
          ],
         ],
         [// face 2
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 7, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 21, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 38, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(7), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(21), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(38), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 9, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 19, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 34, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(9), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(19), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(34), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 14, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 20, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 36, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(14), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(20), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(36), ccw_rotation_60: 3},
             ]  // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 3, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 13, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 29, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(3), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(13), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(29), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 1, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 7, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 21, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(1), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(7), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(21), ccw_rotation_60: 0},
             ],   // j 1
             [
-                BaseCellRotation{base_cell: 6, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 9, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 19, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(6), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(9), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(19), ccw_rotation_60: 0},
             ]    // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 4, ccw_rotation_60: 2},
-                BaseCellRotation{base_cell: 12, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 26, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(4), ccw_rotation_60: 2},
+                BaseCellRotation{base_cell: BaseCell(12), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(26), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 0, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 3, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 13, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(0), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(3), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(13), ccw_rotation_60: 5},
             ],   // j 1
             [
-                BaseCellRotation{base_cell: 2, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 1, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 7, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(2), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(1), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(7), ccw_rotation_60: 0},
             ]     // j 2
          ],
         ],
@@ -376,55 +394,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 26, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 42, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 58, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(26), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(42), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(58), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 29, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 43, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 62, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(29), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(43), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(62), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 38, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 47, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 64, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(38), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(47), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(64), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 12, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 28, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 44, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(12), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(28), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(44), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 13, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 26, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 42, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(13), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(26), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(42), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 21, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 29, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 43, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(21), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(29), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(43), ccw_rotation_60: 0},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 4, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 15, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 31, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(4), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(15), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(31), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 3, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 12, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 28, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(3), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(12), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(28), ccw_rotation_60: 5},
                 ],  // j 1
             [
-                BaseCellRotation{base_cell: 7, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 13, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 26, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(7), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(13), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(26), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -432,55 +450,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 31, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 41, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 49, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(31), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(41), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(49), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 44, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 53, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 61, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(44), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(53), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(61), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 58, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 65, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 75, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(58), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(65), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(75), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 15, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 22, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 33, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(15), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(22), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(33), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 28, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 31, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 41, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(28), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(31), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(41), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 42, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 44, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 53, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(42), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(44), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(53), ccw_rotation_60: 0},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 4, ccw_rotation_60: 4},
-                BaseCellRotation{base_cell: 8, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 16, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(4), ccw_rotation_60: 4},
+                BaseCellRotation{base_cell: BaseCell(8), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(16), ccw_rotation_60: 5},
             ],    // j 0
             [
-                BaseCellRotation{base_cell: 12, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 15, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 22, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(12), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(15), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(22), ccw_rotation_60: 5},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 26, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 28, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 31, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(26), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(28), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(31), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -488,55 +506,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 50, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 48, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 49, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(50), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(48), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(49), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 32, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 30, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 33, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(32), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(30), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(33), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 24, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 18, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 16, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(24), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(18), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(16), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 70, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 67, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 66, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(70), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(67), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(66), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 52, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 50, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 48, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(52), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(50), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(48), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 37, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 32, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 30, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(37), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(32), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(30), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 83, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 87, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 85, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(83), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(87), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(85), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 74, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 70, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 67, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(74), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(70), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(67), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 57, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 52, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 50, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(57), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(52), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(50), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -544,55 +562,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 25, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 23, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 24, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(25), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(23), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(24), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 17, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 11, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 10, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(17), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(11), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(10), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 14, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 6, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 2, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(14), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(6), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(2), ccw_rotation_60: 3},
             ]     // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 45, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 39, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 37, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(45), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(39), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(37), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 35, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 25, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 23, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(35), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(25), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(23), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 27, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 17, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 11, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(27), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(17), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(11), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 63, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 59, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 57, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(63), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(59), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(57), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 56, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 45, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 39, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(56), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(45), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(39), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 46, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 35, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 25, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(46), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(35), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(25), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -600,55 +618,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 36, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 20, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 14, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(36), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(20), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(14), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 34, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 19, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 9, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(34), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(19), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(9), ccw_rotation_60: 3},
             ],   // j 1
             [
-                BaseCellRotation{base_cell: 38, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 21, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 7, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(38), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(21), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(7), ccw_rotation_60: 3},
             ]    // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 55, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 40, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 27, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(55), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(40), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(27), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 54, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 36, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 20, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(54), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(36), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(20), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 51, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 34, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 19, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(51), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(34), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(19), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 72, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 60, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 46, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(72), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(60), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(46), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 73, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 55, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 40, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(73), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(55), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(40), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 71, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 54, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 36, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(71), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(54), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(36), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -656,55 +674,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 64, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 47, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 38, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(64), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(47), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(38), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 62, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 43, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 29, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(62), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(43), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(29), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 58, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 42, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 26, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(58), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(42), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(26), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1BaseCellRotation
             [
-                BaseCellRotation{base_cell: 84, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 69, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 51, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(84), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(69), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(51), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 82, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 64, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 47, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(82), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(64), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(47), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 76, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 62, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 43, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(76), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(62), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(43), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 97, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 89, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 71, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(97), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(89), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(71), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 98, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 84, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 69, ccw_rotation_60: 0}
+                BaseCellRotation{base_cell: BaseCell(98), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(84), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(69), ccw_rotation_60: 0}
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 96, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 82, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 64, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(96), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(82), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(64), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -712,55 +730,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 75, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 65, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 58, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(75), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(65), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(58), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 61, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 53, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 44, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(61), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(53), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(44), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 49, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 41, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 31, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(49), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(41), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(31), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 94, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 86, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 76, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(94), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(86), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(76), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 81, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 75, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 65, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(81), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(75), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(65), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 66, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 61, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 53, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(66), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(61), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(53), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 107, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 104, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 96, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(107), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(104), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(96), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 101, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 94, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 86, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(101), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(94), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(86), ccw_rotation_60: 0},
             ],   // j 1
             [
-                BaseCellRotation{base_cell: 85, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 81, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 75, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(85), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(81), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(75), ccw_rotation_60: 0},
             ]     // j 2
          ],
         ],
@@ -768,55 +786,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 57, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 59, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 63, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(57), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(59), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(63), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 74, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 78, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 79, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(74), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(78), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(79), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 83, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 92, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 95, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(83), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(92), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(95), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 37, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 39, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 45, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(37), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(39), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(45), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 52, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 57, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 59, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(52), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(57), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(59), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 70, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 74, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 78, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(70), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(74), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(78), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 24, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 23, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 25, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(24), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(23), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(25), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 32, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 37, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 39, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(32), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(37), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(39), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 50, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 52, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 57, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(50), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(52), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(57), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -824,55 +842,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 46, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 60, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 72, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(46), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(60), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(72), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 56, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 68, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 80, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(56), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(68), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(80), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 63, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 77, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 90, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(63), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(77), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(90), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 27, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 40, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 55, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(27), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(40), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(55), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 35, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 46, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 60, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(35), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(46), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(60), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 45, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 56, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 68, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(45), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(56), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(68), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 14, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 20, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 36, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(14), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(20), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(36), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 17, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 27, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 40, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(17), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(27), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(40), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 25, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 35, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 46, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(25), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(35), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(46), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -880,55 +898,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 71, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 89, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 97, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(71), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(89), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(97), ccw_rotation_60: 3},
             ],   // j 0
             [
-                BaseCellRotation{base_cell: 73, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 91, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 103, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(73), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(91), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(103), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 72, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 88, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 105, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(72), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(88), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(105), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 51, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 69, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 84, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(51), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(69), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(84), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 54, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 71, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 89, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(54), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(71), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(89), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 55, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 73, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 91, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(55), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(73), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(91), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 38, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 47, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 64, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(38), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(47), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(64), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 34, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 51, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 69, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(34), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(51), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(69), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 36, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 54, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 71, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(36), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(54), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(71), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -936,55 +954,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 96, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 104, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 107, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(96), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(104), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(107), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 98, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 110, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 115, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(98), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(110), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(115), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 97, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 111, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 119, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(97), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(111), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(119), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 76, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 86, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 94, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(76), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(86), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(94), ccw_rotation_60: 3},
             ],   // j 0
             [
-                BaseCellRotation{base_cell: 82, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 96, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 104, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(82), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(96), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(104), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 84, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 98, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 110, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(84), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(98), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(110), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 58, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 65, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 75, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(58), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(65), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(75), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 62, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 76, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 86, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(62), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(76), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(86), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 64, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 82, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 96, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(64), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(82), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(96), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -992,55 +1010,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 85, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 87, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 83, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(85), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(87), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(83), ccw_rotation_60: 3},
             ],     // j 0
             [
-                BaseCellRotation{base_cell: 101, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 102, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 100, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(101), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(102), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(100), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 107, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 112, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 114, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(107), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(112), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(114), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 66, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 67, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 70, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(66), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(67), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(70), ccw_rotation_60: 3},
             ],   // j 0
             [
-                BaseCellRotation{base_cell: 81, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 85, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 87, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(81), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(85), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(87), ccw_rotation_60: 0},
             ],   // j 1
             [
-                BaseCellRotation{base_cell: 94, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 101, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 102, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(94), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(101), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(102), ccw_rotation_60: 3},
             ]  // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 49, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 48, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 50, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(49), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(48), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(50), ccw_rotation_60: 3},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 61, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 66, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 67, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(61), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(66), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(67), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 75, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 81, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 85, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(75), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(81), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(85), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -1048,55 +1066,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 95, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 92, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 83, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(95), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(92), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(83), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 79, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 78, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 74, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(79), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(78), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(74), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 63, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 59, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 57, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(63), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(59), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(57), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 109, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 108, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 100, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(109), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(108), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(100), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 93, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 95, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 92, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(93), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(95), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(92), ccw_rotation_60: 0},
             ],     // j 1
             [
-                BaseCellRotation{base_cell: 77, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 79, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 78, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(77), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(79), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(78), ccw_rotation_60: 0},
             ]      // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 117, ccw_rotation_60: 4},
-                BaseCellRotation{base_cell: 118, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 114, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(117), ccw_rotation_60: 4},
+                BaseCellRotation{base_cell: BaseCell(118), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(114), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 106, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 109, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 108, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(106), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(109), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(108), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 90, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 93, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 95, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(90), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(93), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(95), ccw_rotation_60: 0},
             ]      // j 2
          ],
         ],
@@ -1104,55 +1122,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 90, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 77, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 63, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(90), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(77), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(63), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 80, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 68, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 56, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(80), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(68), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(56), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 72, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 60, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 46, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(72), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(60), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(46), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 106, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 93, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 79, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(106), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(93), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(79), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 99, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 90, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 77, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(99), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(90), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(77), ccw_rotation_60: 0},
             ],   // j 1
             [
-                BaseCellRotation{base_cell: 88, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 80, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 68, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(88), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(80), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(68), ccw_rotation_60: 0},
             ]    // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 117, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 109, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 95, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(117), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(109), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(95), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 113, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 106, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 93, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(113), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(106), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(93), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 105, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 99, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 90, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(105), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(99), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(90), ccw_rotation_60: 0},
             ]    // j 2
          ],
         ],
@@ -1160,55 +1178,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 105, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 88, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 72, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(105), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(88), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(72), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 103, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 91, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 73, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(103), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(91), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(73), ccw_rotation_60: 3},
                 ],  // j 1
             [
-                BaseCellRotation{base_cell: 97, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 89, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 71, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(97), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(89), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(71), ccw_rotation_60: 3},
             ]    // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 113, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 99, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 80, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(113), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(99), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(80), ccw_rotation_60: 5},
             ],   // j 0
             [
-                BaseCellRotation{base_cell: 116, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 105, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 88, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(116), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(105), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(88), ccw_rotation_60: 0},
                 ],  // j 1
             [
-                BaseCellRotation{base_cell: 111, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 103, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 91, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(111), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(103), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(91), ccw_rotation_60: 0},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 117, ccw_rotation_60: 2},
-                BaseCellRotation{base_cell: 106, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 90, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(117), ccw_rotation_60: 2},
+                BaseCellRotation{base_cell: BaseCell(106), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(90), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 121, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 113, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 99, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(121), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(113), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(99), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 119, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 116, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 105, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(119), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(116), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(105), ccw_rotation_60: 0},
             ]  // j 2
          ],
         ],
@@ -1216,55 +1234,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 119, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 111, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 97, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(119), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(111), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(97), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 115, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 110, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 98, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(115), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(110), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(98), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 107, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 104, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 96, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(107), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(104), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(96), ccw_rotation_60: 3},
             ]   // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 121, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 116, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 103, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(121), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(116), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(103), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 120, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 119, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 111, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(120), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(119), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(111), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 112, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 115, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 110, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(112), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(115), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(110), ccw_rotation_60: 0},
             ]   // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 117, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 113, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 105, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(117), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(113), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(105), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 118, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 121, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 116, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(118), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(121), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(116), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 114, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 120, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 119, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(114), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(120), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(119), ccw_rotation_60: 0},
             ]   // j 2
          ],
         ],
@@ -1272,55 +1290,55 @@ lazy_static! {
          [
              // i 0
             [
-                BaseCellRotation{base_cell: 114, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 112, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 107, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(114), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(112), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(107), ccw_rotation_60: 0},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 100, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 102, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 101, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(100), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(102), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(101), ccw_rotation_60: 3},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 83, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 87, ccw_rotation_60: 3},
-                BaseCellRotation{base_cell: 85, ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(83), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(87), ccw_rotation_60: 3},
+                BaseCellRotation{base_cell: BaseCell(85), ccw_rotation_60: 3},
             ]      // j 2
          ],
          [
              // i 1
             [
-                BaseCellRotation{base_cell: 118, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 120, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 115, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(118), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(120), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(115), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 108, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 114, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 112, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(108), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(114), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(112), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 92, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 100, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 102, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(92), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(100), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(102), ccw_rotation_60: 0},
             ]    // j 2
          ],
          [
              // i 2
             [
-                BaseCellRotation{base_cell: 117, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 121, ccw_rotation_60: 5},
-                BaseCellRotation{base_cell: 119, ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(117), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(121), ccw_rotation_60: 5},
+                BaseCellRotation{base_cell: BaseCell(119), ccw_rotation_60: 5},
             ],  // j 0
             [
-                BaseCellRotation{base_cell: 109, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 118, ccw_rotation_60: 0},
-                BaseCellRotation{base_cell: 120, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(109), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(118), ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(120), ccw_rotation_60: 0},
             ],  // j 1
             [
-                BaseCellRotation{base_cell: 95, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 108, ccw_rotation_60: 1},
-                BaseCellRotation{base_cell: 114, ccw_rotation_60: 0},
+                BaseCellRotation{base_cell: BaseCell(95), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(108), ccw_rotation_60: 1},
+                BaseCellRotation{base_cell: BaseCell(114), ccw_rotation_60: 0},
             ]    // j 2
          ]
         ]
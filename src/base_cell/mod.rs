@@ -1,6 +1,12 @@
 pub(crate) mod consts;
 
-use crate::face::Face;
+use crate::{
+    consts::NUM_PENT_VERTS,
+    coordinate::Coordinate,
+    direction::Direction,
+    face::Face,
+    result::{H3ErrorCode, Result},
+};
 pub use consts::*;
 
 use derive_new::new;
@@ -14,20 +20,133 @@ pub struct BaseCellData {
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, new)]
 pub struct BaseCellRotation {
-    /// base cell number
-    pub base_cell: isize,
+    /// base cell
+    pub base_cell: BaseCell,
     /// number of counter-clockwise 60 degree rotations
     /// relative to the current face.
     pub ccw_rotation_60: isize,
 }
 
-/// Returns whether or not the indicated base cell is a pentagon.
-pub fn is_base_cell_pentagon(base_cell: usize) -> bool {
-    if base_cell >= NUM_BASE_CELLS {
-        return false;
+/// One of the [`NUM_BASE_CELLS`] base cells, the resolution 0 cells from
+/// which all other cells descend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BaseCell(u8);
+
+impl BaseCell {
+    /// Returns an iterator over all the base cells, in order.
+    pub fn iter() -> impl Iterator<Item = BaseCell> {
+        (0..NUM_BASE_CELLS as u8).map(BaseCell)
+    }
+
+    /// Returns whether the base cell is a pentagon.
+    pub fn is_pentagon(self) -> bool {
+        self.data().is_pentagon
+    }
+
+    /// Returns whether the base cell is a pentagon where all neighbors are
+    /// oriented toward it.
+    pub fn is_polar_pentagon(self) -> bool {
+        is_base_cell_polar_pentagon(self.into())
+    }
+
+    /// Returns whether the base cell is a pentagon that is offset clockwise
+    /// on `face`.
+    pub fn is_cw_offset(self, face: isize) -> bool {
+        self.is_pentagon() && self.data().cw_offset_pentagon.contains(&face)
+    }
+
+    /// Returns the "home" [`Face`] address of the base cell.
+    pub fn to_face_ijk(self) -> Face {
+        self.data().home_face.clone()
+    }
+
+    /// Finds the base cell neighboring this one in direction `dir`. Returns
+    /// `None` if there is no neighbor in that direction, i.e. the deleted k
+    /// subsequence of a pentagon, or if `dir` is not a direction.
+    pub fn neighbor(self, dir: Direction) -> Option<BaseCell> {
+        let neighbor = *BASE_CELL_NEIGHBORS[usize::from(self)].get(usize::from(dir))?;
+
+        (neighbor != INVALID_BASE_CELL).then_some(BaseCell(neighbor as u8))
+    }
+
+    /// Finds the number of counter-clockwise 60 degree rotations to the
+    /// coordinate system of the base cell neighboring this one in direction
+    /// `dir`. Returns `None` if there is no neighbor in that direction.
+    pub fn neighbor_rotations(self, dir: Direction) -> Option<isize> {
+        let rotations =
+            *BASE_CELL_NEIGHBORS_60_CCW_ROTATIONS[usize::from(self)].get(usize::from(dir))?;
+
+        (rotations != INVALID_ROTATIONS).then_some(rotations)
+    }
+
+    /// Finds the direction from this base cell to `neighbor`. Returns `None`
+    /// if the base cells are not neighbors.
+    pub fn direction(self, neighbor: BaseCell) -> Option<Direction> {
+        BASE_CELL_NEIGHBORS[usize::from(self)]
+            .iter()
+            .position(|&bc| bc == isize::from(neighbor.0))
+            .map(Direction::from)
+    }
+
+    /// Finds the number of counter-clockwise 60 degree rotations from the
+    /// coordinate system of `face` to that of the base cell. Returns `None`
+    /// if the base cell does not appear on the face.
+    pub fn ccw_rot60(self, face: isize) -> Option<isize> {
+        let face = usize::try_from(face).ok()?;
+
+        FACE_IJK_BASE_CELLS
+            .get(face)?
+            .iter()
+            .flatten()
+            .flatten()
+            .find(|rotation| rotation.base_cell == self)
+            .map(|rotation| rotation.ccw_rotation_60)
+    }
+
+    /// Returns the faces neighboring the pentagon base cell in each
+    /// direction, starting at J. Returns `None` if the base cell is not a
+    /// pentagon.
+    pub fn pentagon_direction_faces(self) -> Option<&'static [isize; NUM_PENT_VERTS]> {
+        PENTAGON_DIRECTION_FACES
+            .iter()
+            .find(|(bc, _)| *bc == usize::from(self))
+            .map(|(_, faces)| faces)
+    }
+
+    fn data(self) -> &'static BaseCellData {
+        &BASE_CELL_DATA[usize::from(self)]
+    }
+}
+
+impl TryFrom<usize> for BaseCell {
+    type Error = H3ErrorCode;
+
+    /// Fails with [`H3ErrorCode::CellInvalid`] if `value` is not a base cell
+    /// number.
+    fn try_from(value: usize) -> Result<Self> {
+        if value >= NUM_BASE_CELLS {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        Ok(BaseCell(value as u8))
     }
+}
 
-    BASE_CELL_DATA[base_cell].is_pentagon
+impl From<BaseCell> for usize {
+    fn from(base_cell: BaseCell) -> Self {
+        base_cell.0 as usize
+    }
+}
+
+impl From<BaseCell> for u64 {
+    fn from(base_cell: BaseCell) -> Self {
+        base_cell.0 as u64
+    }
+}
+
+/// Returns whether or not the indicated base cell is a pentagon.
+pub fn is_base_cell_pentagon(base_cell: usize) -> bool {
+    BaseCell::try_from(base_cell).is_ok_and(BaseCell::is_pentagon)
 }
 
 /// Returns whether the indicated base cell is a pentagon where all
@@ -35,3 +154,160 @@ pub fn is_base_cell_pentagon(base_cell: usize) -> bool {
 pub fn is_base_cell_polar_pentagon(base_cell: usize) -> bool {
     base_cell == 4 || base_cell == 117
 }
+
+/// Finds the base cell of a [`Face`] address at resolution 0. Returns `None`
+/// if any coordinate is out of range.
+pub fn face_ijk_to_base_cell(h: &Face) -> Option<BaseCell> {
+    face_ijk_to_base_cell_rotation(h).map(|rotation| rotation.base_cell)
+}
+
+/// Finds the number of counter-clockwise 60 degree rotations from the
+/// coordinate system of a [`Face`] address at resolution 0 to that of its
+/// base cell. Returns `None` if any coordinate is out of range.
+pub fn face_ijk_to_base_cell_ccw_rot60(h: &Face) -> Option<isize> {
+    face_ijk_to_base_cell_rotation(h).map(|rotation| rotation.ccw_rotation_60)
+}
+
+/// Finds the base cell of a [`Face`] address at resolution 0, along with the
+/// number of counter-clockwise 60 degree rotations to the base cell's
+/// coordinate system. Returns `None` if any coordinate is out of range.
+pub fn face_ijk_to_base_cell_rotation(h: &Face) -> Option<&'static BaseCellRotation> {
+    let Coordinate { i, j, k } = h.coord;
+    let max = MAX_FACE_COORD as isize;
+
+    if h.face < 0
+        || h.face >= NUM_ICOSA_FACES as isize
+        || [i, j, k].iter().any(|c| !(0..=max).contains(c))
+    {
+        return None;
+    }
+
+    Some(&FACE_IJK_BASE_CELLS[h.face as usize][i as usize][j as usize][k as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIRECTIONS: [Direction; 7] = [
+        Direction::Center,
+        Direction::KAxes,
+        Direction::JAxes,
+        Direction::JKAxes,
+        Direction::IAxes,
+        Direction::IKAxes,
+        Direction::IJAxes,
+    ];
+
+    #[test]
+    fn base_cell_try_from() {
+        assert_eq!(NUM_BASE_CELLS, BaseCell::iter().count());
+        for (n, bc) in BaseCell::iter().enumerate() {
+            assert_eq!(Ok(bc), BaseCell::try_from(n));
+            assert_eq!(n, usize::from(bc));
+        }
+
+        assert_eq!(
+            Err(H3ErrorCode::CellInvalid),
+            BaseCell::try_from(NUM_BASE_CELLS)
+        );
+        assert_eq!(
+            Err(H3ErrorCode::CellInvalid),
+            BaseCell::try_from(INVALID_BASE_CELL as usize)
+        );
+        assert!(!is_base_cell_pentagon(NUM_BASE_CELLS));
+    }
+
+    #[test]
+    fn base_cell_face_ijk_round_trip() {
+        for bc in BaseCell::iter() {
+            let home = bc.to_face_ijk();
+            assert_eq!(Some(bc), face_ijk_to_base_cell(&home));
+            assert_eq!(Some(0), face_ijk_to_base_cell_ccw_rot60(&home));
+        }
+
+        assert_eq!(
+            None,
+            face_ijk_to_base_cell(&Face::new(0, Coordinate::new(3, 0, 0)))
+        );
+        assert_eq!(
+            None,
+            face_ijk_to_base_cell(&Face::new(NUM_ICOSA_FACES as isize, Coordinate::default()))
+        );
+    }
+
+    #[test]
+    fn base_cell_neighbors() {
+        for bc in BaseCell::iter() {
+            assert_eq!(Some(bc), bc.neighbor(Direction::Center));
+            assert_eq!(Some(Direction::Center), bc.direction(bc));
+
+            for dir in DIRECTIONS {
+                match bc.neighbor(dir) {
+                    Some(neighbor) => {
+                        assert_eq!(Some(dir), bc.direction(neighbor));
+                        assert!(neighbor.direction(bc).is_some());
+                        assert!(bc.neighbor_rotations(dir).is_some());
+                    }
+                    None => {
+                        // only the deleted k subsequence of a pentagon
+                        assert!(bc.is_pentagon());
+                        assert_eq!(Direction::KAxes, dir);
+                        assert_eq!(None, bc.neighbor_rotations(dir));
+                    }
+                }
+            }
+        }
+
+        let bc = BaseCell::try_from(0).unwrap();
+        assert_eq!(None, bc.neighbor(Direction::Invalid));
+        assert_eq!(None, bc.direction(BaseCell::try_from(121).unwrap()));
+
+        // the sentinel in the deleted k direction is not a neighbor
+        let pentagon = BaseCell::try_from(4).unwrap();
+        assert!(pentagon.is_pentagon());
+        assert_eq!(None, pentagon.neighbor(Direction::KAxes));
+    }
+
+    #[test]
+    fn base_cell_cw_offset() {
+        for bc in BaseCell::iter() {
+            let offset_faces = (0..NUM_ICOSA_FACES as isize)
+                .filter(|&face| bc.is_cw_offset(face))
+                .count();
+
+            if bc.is_pentagon() && !bc.is_polar_pentagon() {
+                assert_eq!(2, offset_faces, "base cell {:?}", bc);
+            } else {
+                assert_eq!(0, offset_faces, "base cell {:?}", bc);
+            }
+        }
+    }
+
+    #[test]
+    fn base_cell_ccw_rot60() {
+        for bc in BaseCell::iter() {
+            assert!(bc.ccw_rot60(bc.to_face_ijk().face).is_some());
+        }
+
+        let bc = BaseCell::try_from(0).unwrap();
+        assert_eq!(None, bc.ccw_rot60(-1));
+        assert_eq!(None, bc.ccw_rot60(NUM_ICOSA_FACES as isize));
+        assert_eq!(None, BaseCell::try_from(121).unwrap().ccw_rot60(0));
+    }
+
+    #[test]
+    fn pentagon_faces() {
+        for bc in BaseCell::iter() {
+            match bc.pentagon_direction_faces() {
+                Some(faces) => {
+                    assert!(bc.is_pentagon());
+                    for &face in faces {
+                        assert!(bc.ccw_rot60(face).is_some());
+                    }
+                }
+                None => assert!(!bc.is_pentagon()),
+            }
+        }
+    }
+}
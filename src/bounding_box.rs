@@ -1,30 +1,262 @@
-use crate::latlng::LatLng;
+use derive_new::new;
 
-#[derive(Debug, PartialEq, PartialOrd)]
+use crate::{
+    consts::{MAX_H3_RES, M_2PI, M_PI},
+    latlng::{constrain_lng, LatLng},
+    result::{H3ErrorCode, Result},
+};
+
+/// Geographic bounding box with coordinates defined in radians.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, new)]
 pub struct BoundingBox {
     /// North Latitude
     pub north: f64,
     /// South Latitude
     pub south: f64,
-    /// East Latitude
+    /// East Longitude
     pub east: f64,
-    /// West Latitude
+    /// West Longitude
     pub west: f64,
 }
 
 impl BoundingBox {
+    /// Creates the bounding box of a loop of coordinates. Loops with an arc of
+    /// more than 180 degrees longitude between consecutive vertices are taken
+    /// to cross the antimeridian, in which case `east` is less than `west`.
+    pub fn from_loop(verts: &[LatLng]) -> Self {
+        if verts.is_empty() {
+            return Self::default();
+        }
+
+        let mut bbox = Self::new(-f64::MAX, f64::MAX, -f64::MAX, f64::MAX);
+        let mut min_pos_lng = f64::MAX;
+        let mut max_neg_lng = -f64::MAX;
+        let mut is_transmeridian = false;
+
+        for (i, coord) in verts.iter().enumerate() {
+            let next = &verts[(i + 1) % verts.len()];
+
+            bbox.south = bbox.south.min(coord.lat);
+            bbox.west = bbox.west.min(coord.lng);
+            bbox.north = bbox.north.max(coord.lat);
+            bbox.east = bbox.east.max(coord.lng);
+
+            // save the min positive and max negative longitude for use in
+            // the transmeridian case
+            if coord.lng > 0. && coord.lng < min_pos_lng {
+                min_pos_lng = coord.lng;
+            }
+            if coord.lng < 0. && coord.lng > max_neg_lng {
+                max_neg_lng = coord.lng;
+            }
+
+            // check for arcs > 180 degrees longitude, flagging as
+            // transmeridian
+            if (coord.lng - next.lng).abs() > M_PI {
+                is_transmeridian = true;
+            }
+        }
+
+        // swap east and west if transmeridian
+        if is_transmeridian {
+            bbox.east = max_neg_lng;
+            bbox.west = min_pos_lng;
+        }
+
+        bbox
+    }
+
+    /// Whether the given bounding box crosses the antimeridian.
     pub fn is_transmeridian(&self) -> bool {
-        false
+        self.east < self.west
     }
 
+    /// Gets the center of the bounding box.
     pub fn center(&self) -> LatLng {
-        LatLng { lat: 0., lng: 0. }
+        let lat = (self.north + self.south) / 2.;
+
+        // if the bbox crosses the antimeridian, shift east 360 degrees
+        let east = if self.is_transmeridian() {
+            self.east + M_2PI
+        } else {
+            self.east
+        };
+
+        LatLng::new(lat, constrain_lng((east + self.west) / 2.))
     }
 
-    pub fn contains(&self, _latlng: &LatLng) -> bool {
-        false
+    /// Whether the bounding box contains a given point.
+    pub fn contains(&self, latlng: &LatLng) -> bool {
+        let lng_contained = if self.is_transmeridian() {
+            // transmeridian case
+            latlng.lng >= self.west || latlng.lng <= self.east
+        } else {
+            // standard case
+            latlng.lng >= self.west && latlng.lng <= self.east
+        };
+
+        latlng.lat >= self.south && latlng.lat <= self.north && lng_contained
+    }
+
+    /// Estimates the number of cells at resolution `res` needed to cover the
+    /// bounding box. The estimate errs on the side of too many cells, so it
+    /// can be used to size buffers.
+    pub fn bbox_hex_estimate(&self, res: usize) -> Result<usize> {
+        // get the area of the pentagon as the maximally-distorted area possible
+        let pentagon_radius_km = pentagon_radius_km(res)?;
+        // area of a regular hexagon is 3/2*sqrt(3) * r * r; the pentagon has
+        // the most distortion (smallest edges) and shares its edges with
+        // hexagons, so the most-distorted hexagons have this area, shrunk by
+        // 20% reasonable fudge factor
+        let pentagon_area_km2 = 0.8 * (2.59807621135 * pentagon_radius_km * pentagon_radius_km);
+
+        // then get the area of the bounding box of the geoloop in question
+        let p1 = LatLng::new(self.north, self.east);
+        let p2 = LatLng::new(self.south, self.west);
+        let d = p1.great_circle_distance_km(&p2);
+        // derived constant based on: https://math.stackexchange.com/a/1921940
+        // clamped to 3 as higher values tend to rapidly drag the estimate to
+        // zero
+        let a = d * d / 3f64.min(((p1.lng - p2.lng) / (p1.lat - p2.lat)).abs());
+
+        // divide the two to get an estimate of the number of hexagons needed
+        estimate(a / pentagon_area_km2)
     }
+}
 
-    // bbox_hex_estimate
-    // line_hex_estimate
+/// Estimates the number of cells at resolution `res` needed to trace the line
+/// between `origin` and `destination`.
+pub fn line_hex_estimate(origin: &LatLng, destination: &LatLng, res: usize) -> Result<usize> {
+    // get the area of the pentagon as the maximally-distorted area possible
+    let pentagon_radius_km = pentagon_radius_km(res)?;
+
+    let dist = origin.great_circle_distance_km(destination);
+
+    estimate(dist / (2. * pentagon_radius_km))
+}
+
+/// Rounds an estimate up to a whole number of cells, with a minimum of one.
+fn estimate(cells: f64) -> Result<usize> {
+    let cells = cells.ceil();
+    if !cells.is_finite() {
+        return Err(H3ErrorCode::Failed);
+    }
+
+    Ok((cells as usize).max(1))
+}
+
+/// Distance in km from the center of the pentagon with base cell 4 to its
+/// first vertex, per resolution.
+const PENTAGON_RADIUS_KM: [f64; MAX_H3_RES as usize + 1] = [
+    1036.8608883908703,
+    352.38042092835224,
+    137.46443347244713,
+    48.6753830617263,
+    19.418771575014503,
+    6.920111109763369,
+    2.7696466086903317,
+    0.9879047908557391,
+    0.39557272779245295,
+    0.14111533138750743,
+    0.05650853110466224,
+    0.020159048895379495,
+    0.00807260936990767,
+    0.002879858326048116,
+    0.001153229133678938,
+    0.0004114082117055349,
+];
+
+/// Finds the distance from the center of a pentagon at resolution `res` to
+/// its first vertex.
+fn pentagon_radius_km(res: usize) -> Result<f64> {
+    PENTAGON_RADIUS_KM
+        .get(res)
+        .copied()
+        .ok_or(H3ErrorCode::ResDomain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{direction::Direction, index::H3Index};
+
+    #[test]
+    fn bbox_contains_and_center() {
+        let bbox = BoundingBox::new(1.1, 0.7, -1.7, -2.2);
+        assert!(!bbox.is_transmeridian());
+        assert!(bbox.contains(&LatLng::new(0.9, -2.)));
+        assert!(bbox.contains(&LatLng::new(1.1, -1.7)));
+        assert!(!bbox.contains(&LatLng::new(1.2, -2.)));
+        assert!(!bbox.contains(&LatLng::new(0.9, -1.6)));
+
+        let center = bbox.center();
+        assert!((center.lat - 0.9).abs() < 1e-12);
+        assert!((center.lng + 1.95).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bbox_transmeridian() {
+        let bbox = BoundingBox::new(1., 0.8, -M_PI + 0.3, M_PI - 0.1);
+        assert!(bbox.is_transmeridian());
+        assert!(bbox.contains(&LatLng::new(0.9, M_PI - 0.05)));
+        assert!(bbox.contains(&LatLng::new(0.9, -M_PI + 0.1)));
+        assert!(!bbox.contains(&LatLng::new(0.9, 0.)));
+
+        let center = bbox.center();
+        assert!((center.lat - 0.9).abs() < 1e-12);
+        assert!((center.lng - (-M_PI + 0.1)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn bbox_from_loop() {
+        assert_eq!(BoundingBox::default(), BoundingBox::from_loop(&[]));
+
+        let verts = [
+            LatLng::new(0.8, 0.3),
+            LatLng::new(0.7, 0.3),
+            LatLng::new(0.7, 0.2),
+            LatLng::new(0.8, 0.2),
+        ];
+        assert_eq!(
+            BoundingBox::new(0.8, 0.7, 0.3, 0.2),
+            BoundingBox::from_loop(&verts)
+        );
+
+        let verts = [
+            LatLng::new(0.4, M_PI - 0.1),
+            LatLng::new(0.4, -M_PI + 0.1),
+            LatLng::new(-0.4, -M_PI + 0.1),
+            LatLng::new(-0.4, M_PI - 0.1),
+        ];
+        let bbox = BoundingBox::from_loop(&verts);
+        assert!(bbox.is_transmeridian());
+        assert_eq!(BoundingBox::new(0.4, -0.4, -M_PI + 0.1, M_PI - 0.1), bbox);
+    }
+
+    #[test]
+    fn hex_estimates() {
+        let bbox = BoundingBox::new(0.66, 0.64, -2.12, -2.14);
+        let coarse = bbox.bbox_hex_estimate(5).unwrap();
+        let fine = bbox.bbox_hex_estimate(9).unwrap();
+        assert!(coarse >= 1);
+        assert!(fine > coarse);
+
+        // degenerate boxes still need at least one cell
+        let point = LatLng::new(0.65, -2.13);
+        assert_eq!(1, line_hex_estimate(&point, &point, 9).unwrap());
+        assert!(line_hex_estimate(&point, &LatLng::new(0.66, -2.12), 9).unwrap() > 1);
+
+        assert_eq!(Err(H3ErrorCode::ResDomain), bbox.bbox_hex_estimate(16));
+    }
+
+    #[test]
+    fn pentagon_radius_table() {
+        for (res, radius) in PENTAGON_RADIUS_KM.iter().enumerate() {
+            let pentagon = H3Index::init(res, 4, Direction::Center);
+            let center = pentagon.to_latlng().unwrap();
+            let boundary = pentagon.boundary().unwrap();
+            let expected = center.great_circle_distance_km(&boundary.verts()[0]);
+            assert!((radius - expected).abs() <= 1e-12 * expected, "res {}", res);
+        }
+    }
 }
@@ -0,0 +1,23 @@
+use crate::{latlng::LatLng, MAX_CELL_BOUNDARY_VERTS};
+
+/// Cell boundary in latitude/longitude.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct CellBoundary {
+    /// number of vertices
+    pub num_verts: usize,
+    /// vertices in ccw order
+    pub verts: [LatLng; MAX_CELL_BOUNDARY_VERTS],
+}
+
+impl CellBoundary {
+    /// Returns the vertices of the boundary in counter-clockwise order.
+    pub fn verts(&self) -> &[LatLng] {
+        &self.verts[..self.num_verts]
+    }
+
+    /// Appends a vertex to the boundary.
+    pub(crate) fn push(&mut self, vert: LatLng) {
+        self.verts[self.num_verts] = vert;
+        self.num_verts += 1;
+    }
+}
@@ -2,7 +2,7 @@
 pub use crate::base_cell::consts::*;
 pub use crate::index::consts::*;
 
-pub const M_PI: f64 = 3.14159265358979323846;
+pub const M_PI: f64 = core::f64::consts::PI;
 /// pi / 2.0
 pub const M_PI_2: f64 = M_PI / 2.;
 /// 2.0 * pi
@@ -13,10 +13,22 @@ pub const M_PI_180: f64 = M_PI / 180.;
 pub const M_180_PI: f64 = 180. / M_PI;
 /// Threshold Epsilon
 pub const EPSILON: f64 = 0.0000000000000001;
+/// Epsilon of ~0.1mm in degrees
+pub const EPSILON_DEG: f64 = 0.000000001;
+/// Epsilon of ~0.1mm in radians
+pub const EPSILON_RAD: f64 = EPSILON_DEG * M_PI_180;
 /// sqrt(3) / 2.0
 pub const M_SQRT3_2: f64 = 0.8660254037844386467637231707529361834714;
 /// sin(60')
 pub const M_SIN60: f64 = M_SQRT3_2;
+/// 1 / sin(60')
+pub const M_RSIN60: f64 = 1.1547005383792515290182975610039149112953;
+/// sqrt(7)
+pub const M_SQRT7: f64 = 2.6457513110645905905016157536392604257102;
+/// 1 / sqrt(7)
+pub const M_RSQRT7: f64 = 0.37796447300922722721451653623418006081576;
+/// 1 / 3
+pub const M_ONETHIRD: f64 = 0.333333333333333333333333333333333333333;
 
 /// Rotation angle between Class II and Class III resolution axes.
 /// asin(sqrt(3.0 / 28.0))
@@ -2,15 +2,22 @@
 /// References two Vec2d cartesian coordinate systems:
 ///
 ///    1. gnomonic: face-centered polyhedral gnomonic projection space with
-///             traditional scaling and x-axes aligned with the face Class II
-///             i-axes.
+///       traditional scaling and x-axes aligned with the face Class II
+///       i-axes.
 ///
 ///    2. hex2d: local face-centered coordinate system scaled a specific H3 grid
-///             resolution unit length and with x-axes aligned with the local
-///             i-axes
+///       resolution unit length and with x-axes aligned with the local
+///       i-axes
+use core::ops::{Add, Mul, Sub};
+
 use derive_new::new;
 use lazy_static::lazy_static;
 
+use crate::{
+    direction::Direction,
+    result::{H3ErrorCode, Result},
+};
+
 lazy_static! {
     pub static ref UNIT_VECS: [Coordinate; 7] = [
         Coordinate{i: 0, j: 0, k: 0},  // direction 0
@@ -24,9 +31,444 @@ lazy_static! {
 }
 
 /// Each axis is spaced 120-degress apart.
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, new)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Eq, Ord, new)]
 pub struct Coordinate {
     pub i: isize,
     pub j: isize,
     pub k: isize,
 }
+
+/// IJ hexagon coordinates.
+///
+/// Each axis is spaced 120 degrees apart.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Eq, Ord, Hash, new)]
+pub struct CoordIJ {
+    pub i: isize,
+    pub j: isize,
+}
+
+impl Coordinate {
+    /// Normalizes ijk coordinates by setting the components to the smallest
+    /// possible values. Consumes `self`.
+    pub fn normalize(self) -> Self {
+        let Self {
+            mut i,
+            mut j,
+            mut k,
+        } = self;
+
+        // remove any negative values
+        if i < 0 {
+            j -= i;
+            k -= i;
+            i = 0;
+        }
+
+        if j < 0 {
+            i -= j;
+            k -= j;
+            j = 0;
+        }
+
+        if k < 0 {
+            i -= k;
+            j -= k;
+            k = 0;
+        }
+
+        // remove the min value if needed
+        let min = i.min(j).min(k);
+        Self::new(i - min, j - min, k - min)
+    }
+
+    /// Finds the normalized ijk coordinates of the indexing parent of a cell
+    /// in a counter-clockwise aperture 7 grid.
+    pub fn up_ap7(self) -> Self {
+        // convert to CoordIJ
+        let i = (self.i - self.k) as f64;
+        let j = (self.j - self.k) as f64;
+
+        Self::new(
+            ((3. * i - j) / 7.).round() as isize,
+            ((i + 2. * j) / 7.).round() as isize,
+            0,
+        )
+        .normalize()
+    }
+
+    /// Finds the normalized ijk coordinates of the indexing parent of a cell
+    /// in a clockwise aperture 7 grid.
+    pub fn up_ap7r(self) -> Self {
+        // convert to CoordIJ
+        let i = (self.i - self.k) as f64;
+        let j = (self.j - self.k) as f64;
+
+        Self::new(
+            ((2. * i + j) / 7.).round() as isize,
+            ((3. * j - i) / 7.).round() as isize,
+            0,
+        )
+        .normalize()
+    }
+
+    /// Finds the normalized ijk coordinates of the hexagon centered on the
+    /// indicated hexagon at the next finer aperture 7 counter-clockwise
+    /// resolution.
+    pub fn down_ap7(self) -> Self {
+        self.down(Self::new(3, 0, 1), Self::new(1, 3, 0), Self::new(0, 1, 3))
+    }
+
+    /// Finds the normalized ijk coordinates of the hexagon centered on the
+    /// indicated hexagon at the next finer aperture 7 clockwise resolution.
+    pub fn down_ap7r(self) -> Self {
+        self.down(Self::new(3, 1, 0), Self::new(0, 3, 1), Self::new(1, 0, 3))
+    }
+
+    /// Finds the normalized ijk coordinates of the hexagon centered on the
+    /// indicated hexagon at the next finer aperture 3 counter-clockwise
+    /// resolution.
+    pub fn down_ap3(self) -> Self {
+        self.down(Self::new(2, 0, 1), Self::new(1, 2, 0), Self::new(0, 1, 2))
+    }
+
+    /// Finds the normalized ijk coordinates of the hexagon centered on the
+    /// indicated hexagon at the next finer aperture 3 clockwise resolution.
+    pub fn down_ap3r(self) -> Self {
+        self.down(Self::new(2, 1, 0), Self::new(0, 2, 1), Self::new(1, 0, 2))
+    }
+
+    /// Rotates ijk coordinates 60 degrees counter-clockwise.
+    pub fn rotate_60_ccw(self) -> Self {
+        self.down(Self::new(1, 1, 0), Self::new(0, 1, 1), Self::new(1, 0, 1))
+    }
+
+    /// Rotates ijk coordinates 60 degrees clockwise.
+    pub fn rotate_60_cw(self) -> Self {
+        self.down(Self::new(1, 0, 1), Self::new(1, 1, 0), Self::new(0, 1, 1))
+    }
+
+    /// Finds the normalized ijk coordinates of the hexagon in the
+    /// `direction` of the current ijk coordinates.
+    pub fn neighbor(self, direction: Direction) -> Self {
+        match direction {
+            Direction::Center | Direction::Invalid | Direction::NumDigits => self,
+            _ => (self + UNIT_VECS[usize::from(direction)]).normalize(),
+        }
+    }
+
+    /// Converts ijk coordinates to cube coordinates.
+    ///
+    /// Cube coordinates satisfy `i + j + k == 0`.
+    pub fn to_cube(self) -> Self {
+        let i = -self.i + self.k;
+        let j = self.j - self.k;
+
+        Self::new(i, j, -i - j)
+    }
+
+    /// Converts `cube` coordinates to normalized ijk coordinates.
+    pub fn from_cube(cube: Self) -> Self {
+        Self::new(-cube.i, cube.j, 0).normalize()
+    }
+
+    /// Rounds floating point cube coordinates to the nearest valid cube
+    /// coordinates.
+    pub fn cube_round(i: f64, j: f64, k: f64) -> Self {
+        let mut ri = i.round() as isize;
+        let mut rj = j.round() as isize;
+        let mut rk = k.round() as isize;
+
+        let i_diff = (ri as f64 - i).abs();
+        let j_diff = (rj as f64 - j).abs();
+        let k_diff = (rk as f64 - k).abs();
+
+        // round, maintaining valid cube coords
+        if i_diff > j_diff && i_diff > k_diff {
+            ri = -rj - rk;
+        } else if j_diff > k_diff {
+            rj = -ri - rk;
+        } else {
+            rk = -ri - rj;
+        }
+
+        Self::new(ri, rj, rk)
+    }
+
+    /// Finds the distance between `self` and `other` in grid cells.
+    pub fn distance(&self, other: &Coordinate) -> isize {
+        let diff = (*self - *other).normalize();
+
+        diff.i.abs().max(diff.j.abs()).max(diff.k.abs())
+    }
+
+    /// Re-expresses `self` in the basis given by the unit vectors `i_vec`,
+    /// `j_vec` and `k_vec`, normalizing the result.
+    fn down(self, i_vec: Self, j_vec: Self, k_vec: Self) -> Self {
+        (i_vec * self.i + j_vec * self.j + k_vec * self.k).normalize()
+    }
+}
+
+impl Add for Coordinate {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.i + rhs.i, self.j + rhs.j, self.k + rhs.k)
+    }
+}
+
+impl Sub for Coordinate {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.i - rhs.i, self.j - rhs.j, self.k - rhs.k)
+    }
+}
+
+impl Mul<isize> for Coordinate {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self::Output {
+        Self::new(self.i * factor, self.j * factor, self.k * factor)
+    }
+}
+
+impl From<&Coordinate> for CoordIJ {
+    /// Transforms coordinates from the IJK+ coordinate system to the IJ
+    /// coordinate system.
+    fn from(ijk: &Coordinate) -> Self {
+        Self::new(ijk.i - ijk.k, ijk.j - ijk.k)
+    }
+}
+
+impl TryFrom<&CoordIJ> for Coordinate {
+    type Error = H3ErrorCode;
+
+    /// Transforms coordinates from the IJ coordinate system to the IJK+
+    /// coordinate system.
+    ///
+    /// Fails with [`H3ErrorCode::Failed`] if normalizing the coordinates
+    /// would overflow.
+    fn try_from(ij: &CoordIJ) -> Result<Self> {
+        // normalizing a negative component subtracts it from the others
+        let (min, max) = (ij.i.min(ij.j), ij.i.max(ij.j));
+        if min < 0 && (max.checked_sub(min).is_none() || 0isize.checked_sub(min).is_none()) {
+            return Err(H3ErrorCode::Failed);
+        }
+
+        Ok(Self::new(ij.i, ij.j, 0).normalize())
+    }
+}
+
+impl From<&Coordinate> for Direction {
+    /// Determines the H3 digit corresponding to a unit vector in ijk
+    /// coordinates. Returns [`Direction::Invalid`] if the coordinates
+    /// are not a unit vector.
+    fn from(ijk: &Coordinate) -> Self {
+        let c = ijk.normalize();
+
+        UNIT_VECS
+            .iter()
+            .position(|unit| *unit == c)
+            .map(Direction::from)
+            .unwrap_or(Direction::Invalid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// All ijk coordinates with components in `-RANGE..=RANGE`.
+    fn coordinates() -> impl Iterator<Item = Coordinate> {
+        const RANGE: isize = 4;
+
+        (-RANGE..=RANGE).flat_map(|i| {
+            (-RANGE..=RANGE)
+                .flat_map(move |j| (-RANGE..=RANGE).map(move |k| Coordinate::new(i, j, k)))
+        })
+    }
+
+    #[test]
+    fn coordinate_normalize() {
+        assert_eq!(
+            Coordinate::new(0, 0, 0),
+            Coordinate::new(2, 2, 2).normalize()
+        );
+        assert_eq!(
+            Coordinate::new(0, 1, 0),
+            Coordinate::new(-1, 0, -1).normalize()
+        );
+        assert_eq!(
+            Coordinate::new(3, 0, 1),
+            Coordinate::new(5, 2, 3).normalize()
+        );
+    }
+
+    #[test]
+    fn coordinate_unit_to_digit() {
+        for (d, unit) in UNIT_VECS.iter().enumerate() {
+            assert_eq!(Direction::from(d), Direction::from(unit));
+            assert_eq!(
+                Direction::from(d),
+                Direction::from(&(*unit + Coordinate::new(2, 2, 2)))
+            );
+        }
+
+        assert_eq!(
+            Direction::Invalid,
+            Direction::from(&Coordinate::new(2, 0, 0))
+        );
+    }
+
+    #[test]
+    fn coordinate_up_down_ap7() {
+        for unit in UNIT_VECS.iter() {
+            assert_eq!(*unit, unit.down_ap7().up_ap7());
+            assert_eq!(*unit, unit.down_ap7r().up_ap7r());
+        }
+    }
+
+    #[test]
+    fn coordinate_arithmetic() {
+        for a in coordinates() {
+            let b = Coordinate::new(1, -2, 3);
+            assert_eq!(a, a + b - b);
+            assert_eq!(a + a + a, a * 3);
+            assert_eq!(a, a * 2 - a);
+        }
+    }
+
+    #[test]
+    fn coordinate_down_ap3() {
+        assert_eq!(
+            Coordinate::new(2, 0, 1),
+            Coordinate::new(1, 0, 0).down_ap3()
+        );
+        assert_eq!(
+            Coordinate::new(2, 1, 0),
+            Coordinate::new(1, 0, 0).down_ap3r()
+        );
+    }
+
+    #[test]
+    fn coordinate_rotate_60() {
+        for c in coordinates() {
+            let n = c.normalize();
+            assert_eq!(n, c.rotate_60_ccw().rotate_60_cw());
+            assert_eq!(n, c.rotate_60_cw().rotate_60_ccw());
+            assert_eq!(n, (0..6).fold(c, |c, _| c.rotate_60_ccw()));
+        }
+
+        // rotating a unit vector matches rotating its digit
+        for d in 1..7 {
+            let digit = Direction::from(d);
+            assert_eq!(
+                digit.rotate_60_ccw(),
+                Direction::from(&UNIT_VECS[d].rotate_60_ccw())
+            );
+            assert_eq!(
+                digit.rotate_60_cw(),
+                Direction::from(&UNIT_VECS[d].rotate_60_cw())
+            );
+        }
+    }
+
+    #[test]
+    fn coordinate_neighbor() {
+        for c in coordinates() {
+            let n = c.normalize();
+            assert_eq!(c, c.neighbor(Direction::Center));
+            assert_eq!(c, c.neighbor(Direction::Invalid));
+
+            for d in 1..7 {
+                let digit = Direction::from(d as usize);
+                let opposite = digit.rotate_60_ccw().rotate_60_ccw().rotate_60_ccw();
+                assert_eq!(n, c.neighbor(digit).neighbor(opposite));
+            }
+        }
+    }
+
+    #[test]
+    fn coordinate_ij_round_trip() {
+        for c in coordinates() {
+            let ij = CoordIJ::from(&c);
+            assert_eq!(Ok(c.normalize()), Coordinate::try_from(&ij));
+            assert_eq!(ij, CoordIJ::from(&c.normalize()));
+        }
+    }
+
+    #[test]
+    fn coordinate_ij_overflow() {
+        for ij in [
+            CoordIJ::new(isize::MIN, 0),
+            CoordIJ::new(0, isize::MIN),
+            CoordIJ::new(isize::MAX, -1),
+            CoordIJ::new(-1, isize::MAX),
+        ] {
+            assert_eq!(Err(H3ErrorCode::Failed), Coordinate::try_from(&ij));
+        }
+
+        assert_eq!(
+            Ok(Coordinate::new(isize::MAX, 0, 0)),
+            Coordinate::try_from(&CoordIJ::new(isize::MAX, 0))
+        );
+        assert_eq!(
+            Ok(Coordinate::new(0, 0, isize::MAX)),
+            Coordinate::try_from(&CoordIJ::new(isize::MIN + 1, isize::MIN + 1))
+        );
+    }
+
+    #[test]
+    fn coordinate_cube_round_trip() {
+        for c in coordinates() {
+            let cube = c.to_cube();
+            assert_eq!(0, cube.i + cube.j + cube.k);
+            assert_eq!(c.normalize(), Coordinate::from_cube(cube));
+        }
+    }
+
+    #[test]
+    fn coordinate_up_down_ap7_round_trip() {
+        for c in coordinates() {
+            let n = c.normalize();
+            assert_eq!(n, c.down_ap7().up_ap7());
+            assert_eq!(n, c.down_ap7r().up_ap7r());
+        }
+    }
+
+    #[test]
+    fn coordinate_distance() {
+        let origin = Coordinate::default();
+        assert_eq!(0, origin.distance(&origin));
+        assert_eq!(1, origin.distance(&Coordinate::new(1, 0, 0)));
+        assert_eq!(2, origin.distance(&Coordinate::new(1, 0, 2)));
+        assert_eq!(3, origin.distance(&Coordinate::new(3, 0, 0)));
+
+        for a in coordinates() {
+            assert_eq!(0, a.distance(&a.normalize()));
+            for dir in 1..7usize {
+                assert_eq!(1, a.distance(&a.neighbor(Direction::from(dir))));
+            }
+        }
+    }
+
+    #[test]
+    fn coordinate_cube_round() {
+        assert_eq!(
+            Coordinate::new(1, -1, 0),
+            Coordinate::cube_round(1.2, -0.9, -0.3)
+        );
+        assert_eq!(
+            Coordinate::new(0, 1, -1),
+            Coordinate::cube_round(0.4, 0.8, -1.2)
+        );
+
+        for c in coordinates() {
+            let cube = c.to_cube();
+            assert_eq!(
+                cube,
+                Coordinate::cube_round(cube.i as f64, cube.j as f64, cube.k as f64)
+            );
+        }
+    }
+}
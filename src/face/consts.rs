@@ -1,3 +1,6 @@
+use super::FaceOrient;
+use crate::{coordinate::Coordinate, geometry::Vec3d, latlng::LatLng, NUM_ICOSA_FACES};
+
 // indexes for faceNeighbors table
 /// IJ quadrant faceNeighbors table direction
 pub const IJ: usize = 1;
@@ -8,3 +11,260 @@ pub const JK: usize = 3;
 
 /// Invalid face index
 pub const INVALID_FACE: isize = -1;
+
+/// Icosahedron face centers in lat/lng radians.
+#[rustfmt::skip]
+pub const FACE_CENTER_GEO: [LatLng; NUM_ICOSA_FACES] = [
+    LatLng{lat: 0.80358264971899, lng: 1.2483974196173961},         // face 0
+    LatLng{lat: 1.3077478834556382, lng: 2.5369450098779214},       // face 1
+    LatLng{lat: 1.054751253523952, lng: -1.3475173589003966},       // face 2
+    LatLng{lat: 0.6001915955381868, lng: -0.45060390946975576},     // face 3
+    LatLng{lat: 0.49171542819877384, lng: 0.40198820291130694},     // face 4
+    LatLng{lat: 0.1727453274156187, lng: 1.6781468852804338},       // face 5
+    LatLng{lat: 0.6059293215713507, lng: 2.9539233298124117},       // face 6
+    LatLng{lat: 0.42737051832897965, lng: -1.8888762003362853},     // face 7
+    LatLng{lat: -0.07906611854921283, lng: -0.7334295133808677},    // face 8
+    LatLng{lat: -0.23096164445538364, lng: 0.506495587332349},      // face 9
+    LatLng{lat: 0.07906611854921283, lng: 2.4081631402089254},      // face 10
+    LatLng{lat: 0.23096164445538364, lng: -2.635097066257444},      // face 11
+    LatLng{lat: -0.1727453274156187, lng: -1.4634457683093596},     // face 12
+    LatLng{lat: -0.6059293215713507, lng: -0.18766932377738163},    // face 13
+    LatLng{lat: -0.42737051832897965, lng: 1.2527164532535078},     // face 14
+    LatLng{lat: -0.6001915955381868, lng: 2.6909887441200375},      // face 15
+    LatLng{lat: -0.49171542819877384, lng: -2.7396044506784865},    // face 16
+    LatLng{lat: -0.80358264971899, lng: -1.8931952339723972},       // face 17
+    LatLng{lat: -1.3077478834556382, lng: -0.6046476437118721},     // face 18
+    LatLng{lat: -1.054751253523952, lng: 1.7940752946893965},       // face 19
+];
+
+/// Icosahedron face centers in x/y/z on the unit sphere.
+#[rustfmt::skip]
+pub const FACE_CENTER_POINT: [Vec3d; NUM_ICOSA_FACES] = [
+    Vec3d{x: 0.2199307791404606, y: 0.6583691780274996, z: 0.7198475378926182},     // face 0
+    Vec3d{x: -0.2139234834501421, y: 0.1478171829550703, z: 0.9656017935214205},    // face 1
+    Vec3d{x: 0.1092625278784797, y: -0.481195157287321, z: 0.8697775121287253},     // face 2
+    Vec3d{x: 0.7428567301586791, y: -0.3593941678278028, z: 0.5648005936517033},    // face 3
+    Vec3d{x: 0.8112534709140969, y: 0.3448953237639384, z: 0.472138773641393},      // face 4
+    Vec3d{x: -0.1055498149613921, y: 0.9794457296411413, z: 0.1718874610009365},    // face 5
+    Vec3d{x: -0.8075407579970092, y: 0.1533552485898818, z: 0.5695261994882688},    // face 6
+    Vec3d{x: -0.2846148069787907, y: -0.8644080972654206, z: 0.4144792552473539},   // face 7
+    Vec3d{x: 0.7405621473854482, y: -0.6673299564565524, z: -0.0789837646326737},   // face 8
+    Vec3d{x: 0.8512303986474293, y: 0.4722343788582681, z: -0.2289137388687808},    // face 9
+    Vec3d{x: -0.7405621473854481, y: 0.6673299564565524, z: 0.0789837646326737},    // face 10
+    Vec3d{x: -0.8512303986474292, y: -0.4722343788582682, z: 0.2289137388687808},   // face 11
+    Vec3d{x: 0.1055498149613919, y: -0.9794457296411413, z: -0.1718874610009365},   // face 12
+    Vec3d{x: 0.8075407579970092, y: -0.1533552485898819, z: -0.5695261994882688},   // face 13
+    Vec3d{x: 0.2846148069787908, y: 0.8644080972654204, z: -0.4144792552473539},    // face 14
+    Vec3d{x: -0.7428567301586791, y: 0.3593941678278027, z: -0.5648005936517033},   // face 15
+    Vec3d{x: -0.811253470914097, y: -0.3448953237639382, z: -0.472138773641393},    // face 16
+    Vec3d{x: -0.2199307791404607, y: -0.6583691780274996, z: -0.7198475378926182},  // face 17
+    Vec3d{x: 0.213923483450142, y: -0.1478171829550704, z: -0.9656017935214205},    // face 18
+    Vec3d{x: -0.1092625278784796, y: 0.481195157287321, z: -0.8697775121287253},    // face 19
+];
+
+/// Definition of which faces neighbor each other.
+#[rustfmt::skip]
+pub const FACE_NEIGHBORS: [[FaceOrient; 4]; NUM_ICOSA_FACES] = [
+    [
+        // face 0
+        FaceOrient{face: 0, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 4, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},        // ij quadrant
+        FaceOrient{face: 1, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},        // ki quadrant
+        FaceOrient{face: 5, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 1
+        FaceOrient{face: 1, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 0, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},        // ij quadrant
+        FaceOrient{face: 2, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},        // ki quadrant
+        FaceOrient{face: 6, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 2
+        FaceOrient{face: 2, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 1, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},        // ij quadrant
+        FaceOrient{face: 3, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},        // ki quadrant
+        FaceOrient{face: 7, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 3
+        FaceOrient{face: 3, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 2, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},        // ij quadrant
+        FaceOrient{face: 4, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},        // ki quadrant
+        FaceOrient{face: 8, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 4
+        FaceOrient{face: 4, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 3, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},        // ij quadrant
+        FaceOrient{face: 0, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},        // ki quadrant
+        FaceOrient{face: 9, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 5
+        FaceOrient{face: 5, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 10, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},       // ij quadrant
+        FaceOrient{face: 14, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},       // ki quadrant
+        FaceOrient{face: 0, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 6
+        FaceOrient{face: 6, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 11, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},       // ij quadrant
+        FaceOrient{face: 10, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},       // ki quadrant
+        FaceOrient{face: 1, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 7
+        FaceOrient{face: 7, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 12, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},       // ij quadrant
+        FaceOrient{face: 11, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},       // ki quadrant
+        FaceOrient{face: 2, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 8
+        FaceOrient{face: 8, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 13, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},       // ij quadrant
+        FaceOrient{face: 12, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},       // ki quadrant
+        FaceOrient{face: 3, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 9
+        FaceOrient{face: 9, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},        // central face
+        FaceOrient{face: 14, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},       // ij quadrant
+        FaceOrient{face: 13, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},       // ki quadrant
+        FaceOrient{face: 4, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},        // jk quadrant
+    ],
+    [
+        // face 10
+        FaceOrient{face: 10, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 5, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},        // ij quadrant
+        FaceOrient{face: 6, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},        // ki quadrant
+        FaceOrient{face: 15, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 11
+        FaceOrient{face: 11, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 6, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},        // ij quadrant
+        FaceOrient{face: 7, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},        // ki quadrant
+        FaceOrient{face: 16, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 12
+        FaceOrient{face: 12, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 7, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},        // ij quadrant
+        FaceOrient{face: 8, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},        // ki quadrant
+        FaceOrient{face: 17, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 13
+        FaceOrient{face: 13, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 8, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},        // ij quadrant
+        FaceOrient{face: 9, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},        // ki quadrant
+        FaceOrient{face: 18, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 14
+        FaceOrient{face: 14, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 9, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 3},        // ij quadrant
+        FaceOrient{face: 5, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 3},        // ki quadrant
+        FaceOrient{face: 19, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 15
+        FaceOrient{face: 15, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 16, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},       // ij quadrant
+        FaceOrient{face: 19, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},       // ki quadrant
+        FaceOrient{face: 10, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 16
+        FaceOrient{face: 16, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 17, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},       // ij quadrant
+        FaceOrient{face: 15, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},       // ki quadrant
+        FaceOrient{face: 11, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 17
+        FaceOrient{face: 17, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 18, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},       // ij quadrant
+        FaceOrient{face: 16, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},       // ki quadrant
+        FaceOrient{face: 12, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 18
+        FaceOrient{face: 18, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 19, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},       // ij quadrant
+        FaceOrient{face: 17, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},       // ki quadrant
+        FaceOrient{face: 13, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+    [
+        // face 19
+        FaceOrient{face: 19, translate: Coordinate{i: 0, j: 0, k: 0}, ccw_rot_60: 0},       // central face
+        FaceOrient{face: 15, translate: Coordinate{i: 2, j: 0, k: 2}, ccw_rot_60: 1},       // ij quadrant
+        FaceOrient{face: 18, translate: Coordinate{i: 2, j: 2, k: 0}, ccw_rot_60: 5},       // ki quadrant
+        FaceOrient{face: 14, translate: Coordinate{i: 0, j: 2, k: 2}, ccw_rot_60: 3},       // jk quadrant
+    ],
+];
+
+/// Icosahedron face ijk axes as azimuth in radians from face center to
+/// vertex 0/1/2 respectively.
+pub const FACE_AXES_AZ_RADS_CII: [[f64; 3]; NUM_ICOSA_FACES] = [
+    [5.6199582685239395, 3.5255631661307447, 1.4311680637375488], // face 0
+    [5.7603390817141875, 3.665943979320992, 1.571548876927796],   // face 1
+    [0.78021365439343, 4.969003859179821, 2.8746087567866256],    // face 2
+    [0.4304693639799999, 4.619259568766391, 2.5248644663731956],  // face 3
+    [6.130269123335111, 4.0358740209419155, 1.9414789185487202],  // face 4
+    [2.692877706530643, 0.5984826041374471, 4.787272808923838],   // face 5
+    [2.982963003477244, 0.8885679010840484, 5.07735810587044],    // face 6
+    [3.532912002790141, 1.4385169003969456, 5.627307105183337],   // face 7
+    [3.494305004259568, 1.3999099018663728, 5.588700106652764],   // face 8
+    [3.0032141694995382, 0.908819067106343, 5.0976092718927335],  // face 9
+    [5.930472956509812, 3.836077854116616, 1.7416827517234204],   // face 10
+    [0.13837848409025486, 4.327168688876646, 2.23277358648345],   // face 11
+    [0.4487149470591504, 4.6375051518455415, 2.543110049452346],  // face 12
+    [0.15862965011254937, 4.3474198548989405, 2.2530247525057447], // face 13
+    [5.891865957979238, 3.797470855586043, 1.7030757531928475],   // face 14
+    [2.711123289609793, 0.6167281872165977, 4.8055183920029885],  // face 15
+    [3.294508837434268, 1.2001137350410729, 5.388903939827464],   // face 16
+    [3.80481969224544, 1.7104245898522445, 5.8992147946386355],   // face 17
+    [3.6644388790551923, 1.570043776661997, 5.758833981448388],   // face 18
+    [2.361378999196363, 0.2669838968031676, 4.455774101589559],   // face 19
+];
+
+/// Indicates which neighboring face is in each quadrant of a face: 0 for the
+/// face itself, [`IJ`], [`KI`] or [`JK`] for a neighbor, and -1 if the faces
+/// are not adjacent.
+#[rustfmt::skip]
+pub const ADJACENT_FACE_DIR: [[isize; NUM_ICOSA_FACES]; NUM_ICOSA_FACES] = [
+    [ 0,  2, -1, -1,  1,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1], // face 0
+    [ 1,  0,  2, -1, -1, -1,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1], // face 1
+    [-1,  1,  0,  2, -1, -1, -1,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1], // face 2
+    [-1, -1,  1,  0,  2, -1, -1, -1,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1], // face 3
+    [ 2, -1, -1,  1,  0, -1, -1, -1, -1,  3, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1], // face 4
+    [ 3, -1, -1, -1, -1,  0, -1, -1, -1, -1,  1, -1, -1, -1,  2, -1, -1, -1, -1, -1], // face 5
+    [-1,  3, -1, -1, -1, -1,  0, -1, -1, -1,  2,  1, -1, -1, -1, -1, -1, -1, -1, -1], // face 6
+    [-1, -1,  3, -1, -1, -1, -1,  0, -1, -1, -1,  2,  1, -1, -1, -1, -1, -1, -1, -1], // face 7
+    [-1, -1, -1,  3, -1, -1, -1, -1,  0, -1, -1, -1,  2,  1, -1, -1, -1, -1, -1, -1], // face 8
+    [-1, -1, -1, -1,  3, -1, -1, -1, -1,  0, -1, -1, -1,  2,  1, -1, -1, -1, -1, -1], // face 9
+    [-1, -1, -1, -1, -1,  1,  2, -1, -1, -1,  0, -1, -1, -1, -1,  3, -1, -1, -1, -1], // face 10
+    [-1, -1, -1, -1, -1, -1,  1,  2, -1, -1, -1,  0, -1, -1, -1, -1,  3, -1, -1, -1], // face 11
+    [-1, -1, -1, -1, -1, -1, -1,  1,  2, -1, -1, -1,  0, -1, -1, -1, -1,  3, -1, -1], // face 12
+    [-1, -1, -1, -1, -1, -1, -1, -1,  1,  2, -1, -1, -1,  0, -1, -1, -1, -1,  3, -1], // face 13
+    [-1, -1, -1, -1, -1,  2, -1, -1, -1,  1, -1, -1, -1, -1,  0, -1, -1, -1, -1,  3], // face 14
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1,  3, -1, -1, -1, -1,  0,  1, -1, -1,  2], // face 15
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,  3, -1, -1, -1,  2,  0,  1, -1, -1], // face 16
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,  3, -1, -1, -1,  2,  0,  1, -1], // face 17
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,  3, -1, -1, -1,  2,  0,  1], // face 18
+    [-1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1, -1,  3,  1, -1, -1,  2,  0], // face 19
+];
+
+/// Overage distance table, indexed by Class II resolution.
+pub const MAX_DIM_BY_CII_RES: [isize; 17] = [
+    2, -1, 14, -1, 98, -1, 686, -1, 4802, -1, 33614, -1, 235298, -1, 1647086, -1, 11529602,
+];
+
+/// Unit scale distance table, indexed by Class II resolution.
+pub const UNIT_SCALE_BY_CII_RES: [isize; 17] = [
+    1, -1, 7, -1, 49, -1, 343, -1, 2401, -1, 16807, -1, 117649, -1, 823543, -1, 5764801,
+];
@@ -1,23 +1,491 @@
 pub(crate) mod consts;
 
-use crate::coordinate::Coordinate;
+use core::{marker::PhantomData, ops::Deref};
+
+use derive_new::new;
+
+use crate::{
+    cell_boundary::CellBoundary,
+    consts::{M_SQRT3_2, NUM_HEX_VERTS, NUM_PENT_VERTS},
+    coordinate::Coordinate,
+    geometry::{geo_to_hex2d, hex2d_to_geo, Vec2d},
+    index::H3Index,
+    is_resolution_class_iii,
+    latlng::LatLng,
+    result::{H3ErrorCode, Result},
+};
+
+/// The vertexes of an origin-centered cell in a Class II resolution on a
+/// substrate grid with aperture sequence 33r. The aperture 3 gets us the
+/// vertices, and the 3r gets us back to Class II. Vertices are listed ccw
+/// from the i-axes; pentagons use the first five.
+const VERTS_CII: [Coordinate; NUM_HEX_VERTS] = [
+    Coordinate { i: 2, j: 1, k: 0 },
+    Coordinate { i: 1, j: 2, k: 0 },
+    Coordinate { i: 0, j: 2, k: 1 },
+    Coordinate { i: 0, j: 1, k: 2 },
+    Coordinate { i: 1, j: 0, k: 2 },
+    Coordinate { i: 2, j: 0, k: 1 },
+];
+
+/// The vertexes of an origin-centered cell in a Class III resolution on a
+/// substrate grid with aperture sequence 33r7r. The aperture 3 gets us the
+/// vertices, and the 3r7r gets us to Class II. Vertices are listed ccw from
+/// the i-axes; pentagons use the first five.
+const VERTS_CIII: [Coordinate; NUM_HEX_VERTS] = [
+    Coordinate { i: 5, j: 4, k: 0 },
+    Coordinate { i: 1, j: 5, k: 0 },
+    Coordinate { i: 0, j: 5, k: 4 },
+    Coordinate { i: 0, j: 1, k: 5 },
+    Coordinate { i: 4, j: 0, k: 5 },
+    Coordinate { i: 5, j: 0, k: 1 },
+];
 
 pub use consts::*;
 
-#[derive(Clone, Debug, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, new)]
 pub struct Face {
     pub face: isize,
     pub coord: Coordinate,
 }
 
+impl Face {
+    /// Encodes a coordinate on the sphere to the [`Face`] address of the
+    /// containing cell at the specified resolution.
+    pub fn from_geo(g: &LatLng, res: usize) -> Self {
+        // first convert to hex2d
+        let (face, v) = geo_to_hex2d(g, res);
+
+        // then convert to ijk+
+        Self {
+            face,
+            coord: (&v).into(),
+        }
+    }
+
+    /// Determines the center point in spherical coordinates of a cell given
+    /// by a [`Face`] address at a specified resolution.
+    ///
+    /// Fails with [`H3ErrorCode::Domain`] if the address is not on an
+    /// icosahedron face.
+    pub fn to_geo(&self, res: usize) -> Result<LatLng> {
+        hex2d_to_geo(&Vec2d::from(&self.coord), self.face, res, false)
+    }
+
+    /// Returns the orientation of the face adjacent to this address's face in
+    /// `quadrant`, one of [`IJ`], [`KI`] or [`JK`]. Quadrant 0 is the face
+    /// itself. Returns `None` if the address is not on an icosahedron face or
+    /// `quadrant` is not a quadrant.
+    pub fn neighbor(&self, quadrant: usize) -> Option<&'static FaceOrient> {
+        FACE_NEIGHBORS
+            .get(usize::try_from(self.face).ok()?)?
+            .get(quadrant)
+    }
+
+    /// Returns the quadrant of this address's face in which `face` lies, or
+    /// `None` if the faces are not adjacent or either is not an icosahedron
+    /// face. A face lies in quadrant 0 of itself.
+    pub fn adjacent_dir(&self, face: isize) -> Option<usize> {
+        let dirs = ADJACENT_FACE_DIR.get(usize::try_from(self.face).ok()?)?;
+        usize::try_from(*dirs.get(usize::try_from(face).ok()?)?).ok()
+    }
+
+    /// Re-expresses the address in the coordinate system of the adjacent face
+    /// described by `orient`, with the face translation scaled to the grid by
+    /// `unit_scale`.
+    pub fn to_neighbor(&self, orient: &FaceOrient, unit_scale: isize) -> Face {
+        // rotate and translate for adjacent face
+        let rotated = (0..orient.ccw_rot_60).fold(self.coord, |ijk, _| ijk.rotate_60_ccw());
+
+        Face {
+            face: orient.face,
+            coord: (rotated + orient.translate * unit_scale).normalize(),
+        }
+    }
+
+    /// Adjusts a [`Face`] address for a pentagon vertex in a substrate grid
+    /// in order to place the vertex on the correct icosahedral face.
+    ///
+    /// Unlike [`Face::adjust_overage_class_ii`], the adjustment is repeated
+    /// until the vertex no longer lands on a new face, so the result is never
+    /// [`Overage::NewFace`].
+    pub fn adjust_pent_vert_overage(&mut self, res: usize) -> Overage {
+        loop {
+            let overage = self.adjust_overage_class_ii(res, false, true);
+            if overage != Overage::NewFace {
+                return overage;
+            }
+        }
+    }
+
+    /// Adjusts a [`Face`] address for a Class II resolution `res` so that the
+    /// resulting cell address is relative to the correct icosahedral face.
+    ///
+    /// `pent_leading_4` indicates whether the address is for a pentagon with a
+    /// leading digit of 4, and `substrate` whether the address is on the
+    /// aperture 3 substrate grid of `res`.
+    ///
+    /// An address that runs off its face is rotated and translated onto the
+    /// adjacent face in the quadrant it overflows into, and
+    /// [`Overage::NewFace`] is returned.
+    pub fn adjust_overage_class_ii(
+        &mut self,
+        res: usize,
+        pent_leading_4: bool,
+        substrate: bool,
+    ) -> Overage {
+        // get the maximum dimension value; scale if a substrate grid
+        let mut max_dim = MAX_DIM_BY_CII_RES[res];
+        if substrate {
+            max_dim *= 3;
+        }
+
+        // check for overage
+        let sum = self.coord.i + self.coord.j + self.coord.k;
+        if substrate && sum == max_dim {
+            // on edge
+            return Overage::FaceEdge;
+        }
+
+        if sum <= max_dim {
+            return Overage::NoOverage;
+        }
+
+        // overage
+        let quadrant = if self.coord.k > 0 {
+            if self.coord.j > 0 {
+                // jk "quadrant"
+                JK
+            } else {
+                // ik "quadrant"
+                // adjust for the pentagonal missing sequence
+                if pent_leading_4 {
+                    // translate origin to center of pentagon, rotate to
+                    // adjust for the missing sequence and translate the
+                    // origin back to the center of the triangle
+                    let origin = Coordinate::new(max_dim, 0, 0);
+                    self.coord = (self.coord - origin).rotate_60_cw() + origin;
+                }
+
+                KI
+            }
+        } else {
+            // ij "quadrant"
+            IJ
+        };
+
+        let mut unit_scale = UNIT_SCALE_BY_CII_RES[res];
+        if substrate {
+            unit_scale *= 3;
+        }
+
+        *self = self.to_neighbor(&FACE_NEIGHBORS[self.face as usize][quadrant], unit_scale);
+
+        // overage points on pentagon boundaries can end up on edges
+        if substrate && self.coord.i + self.coord.j + self.coord.k == max_dim {
+            Overage::FaceEdge
+        } else {
+            Overage::NewFace
+        }
+    }
+}
+
+/// Marker for cells with [`NUM_HEX_VERTS`] vertices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Hexagon;
+
+/// Marker for cells with [`NUM_PENT_VERTS`] vertices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Pentagon;
+
+/// The shape of a cell, either [`Hexagon`] or [`Pentagon`].
+pub trait Shape {
+    /// Whether cells of this shape are pentagons.
+    const IS_PENTAGON: bool;
+    /// The number of vertices of cells of this shape.
+    const NUM_VERTS: usize;
+}
+
+impl Shape for Hexagon {
+    const IS_PENTAGON: bool = false;
+    const NUM_VERTS: usize = NUM_HEX_VERTS;
+}
+
+impl Shape for Pentagon {
+    const IS_PENTAGON: bool = true;
+    const NUM_VERTS: usize = NUM_PENT_VERTS;
+}
+
+/// A [`Face`] address of a cell whose shape is known at compile time, so
+/// that the hexagon and pentagon variants of the face algorithms are
+/// selected by type rather than by runtime checks.
+#[derive(Clone, Debug, PartialEq, PartialOrd)]
+pub struct FaceIJK<T> {
+    fijk: Face,
+    _m: PhantomData<T>,
+}
+
+impl<T: Shape> FaceIJK<T> {
+    /// Converts the address to the corresponding cell at resolution `res`.
+    pub fn to_h3(&self, res: usize) -> Result<H3Index> {
+        H3Index::from_face_ijk(&self.fijk, res)
+    }
+}
+
+impl<T> Deref for FaceIJK<T> {
+    type Target = Face;
+
+    fn deref(&self) -> &Self::Target {
+        &self.fijk
+    }
+}
+
+impl<T: Shape> TryFrom<&H3Index> for FaceIJK<T> {
+    type Error = H3ErrorCode;
+
+    /// Converts a cell to its [`Face`] address, failing with
+    /// [`H3ErrorCode::Pentagon`] for a pentagon converted to a [`Hexagon`]
+    /// and [`H3ErrorCode::CellInvalid`] for a hexagon converted to a
+    /// [`Pentagon`].
+    fn try_from(h3: &H3Index) -> Result<Self> {
+        match (h3.is_pentagon(), T::IS_PENTAGON) {
+            (true, false) => Err(H3ErrorCode::Pentagon),
+            (false, true) => Err(H3ErrorCode::CellInvalid),
+            _ => Ok(Self {
+                fijk: h3.to_face_ijk()?,
+                _m: PhantomData,
+            }),
+        }
+    }
+}
+
+impl FaceIJK<Hexagon> {
+    /// Gets the vertices of the cell as substrate [`Face`] addresses, along
+    /// with the adjusted (Class II) resolution of the substrate grid.
+    pub fn verts(&self, res: usize) -> ([Face; NUM_HEX_VERTS], usize) {
+        substrate_verts(&self.fijk, res)
+    }
+
+    /// Generates the cell boundary in spherical coordinates for the cell at
+    /// a specified resolution, starting at vertex `start` and covering
+    /// `length` vertices.
+    ///
+    /// Fails with [`H3ErrorCode::Domain`] if the address is not on an
+    /// icosahedron face.
+    pub fn to_cell_boundary(
+        &self,
+        res: usize,
+        start: usize,
+        length: usize,
+    ) -> Result<CellBoundary> {
+        let (verts, adj_res) = self.verts(res);
+
+        // If we're returning the entire loop, we need one more iteration in
+        // case of a distortion vertex on the last edge
+        let additional_iteration = usize::from(length == NUM_HEX_VERTS);
+
+        // convert each vertex to lat/lng
+        // adjust the face of each vertex as appropriate and introduce
+        // edge-crossing vertices as needed
+        let mut boundary = CellBoundary::default();
+        let mut last_face = -1;
+        let mut last_overage = Overage::NoOverage;
+        for vert in start..start + length + additional_iteration {
+            let v = vert % NUM_HEX_VERTS;
+
+            let mut fijk = verts[v].clone();
+            let overage = fijk.adjust_overage_class_ii(adj_res, false, true);
+
+            // Check for edge-crossing. Each face of the underlying icosahedron
+            // is a different projection plane. So if an edge of the hexagon
+            // crosses an icosahedron edge, an additional vertex must be
+            // introduced at that intersection point. Then each half of the
+            // cell edge can be projected to geographic coordinates using the
+            // appropriate icosahedron face projection. Note that Class II cell
+            // edges have vertices on the face edge, with no edge line
+            // intersections.
+            if is_resolution_class_iii(res)
+                && vert > start
+                && fijk.face != last_face
+                && last_overage != Overage::FaceEdge
+            {
+                // find hex2d of the two vertexes on original face
+                let last_v = (v + 5) % NUM_HEX_VERTS;
+                let orig2d0 = Vec2d::from(&verts[last_v].coord);
+                let orig2d1 = Vec2d::from(&verts[v].coord);
+
+                // find the appropriate icosa face edge vertexes
+                let face2 = if last_face == self.face {
+                    fijk.face
+                } else {
+                    last_face
+                };
+                let (edge0, edge1) = icosa_edge(adj_res, self.adjacent_dir(face2));
+
+                // find the intersection and add the lat/lng point to the result
+                let inter = Vec2d::intersect(&orig2d0, &orig2d1, &edge0, &edge1);
+
+                // If a point of intersection occurs at a hexagon vertex, then
+                // each adjacent hexagon edge will lie completely on a single
+                // icosahedron face, and no additional vertex is required.
+                if !orig2d0.almost_equals(&inter) && !orig2d1.almost_equals(&inter) {
+                    boundary.push(hex2d_to_geo(&inter, self.face, adj_res, true)?);
+                }
+            }
+
+            // convert vertex to lat/lng and add to the result
+            // vert == start + NUM_HEX_VERTS is only used to test for possible
+            // intersection on last edge
+            if vert < start + NUM_HEX_VERTS {
+                let vec = Vec2d::from(&fijk.coord);
+                boundary.push(hex2d_to_geo(&vec, fijk.face, adj_res, true)?);
+            }
+
+            last_face = fijk.face;
+            last_overage = overage;
+        }
+
+        Ok(boundary)
+    }
+}
+
+impl FaceIJK<Pentagon> {
+    /// Gets the vertices of the cell as substrate [`Face`] addresses, along
+    /// with the adjusted (Class II) resolution of the substrate grid.
+    pub fn verts(&self, res: usize) -> ([Face; NUM_PENT_VERTS], usize) {
+        substrate_verts(&self.fijk, res)
+    }
+
+    /// Generates the cell boundary in spherical coordinates for the cell at
+    /// a specified resolution, starting at vertex `start` and covering
+    /// `length` vertices.
+    ///
+    /// Fails with [`H3ErrorCode::Domain`] if the address is not on an
+    /// icosahedron face.
+    pub fn to_cell_boundary(
+        &self,
+        res: usize,
+        start: usize,
+        length: usize,
+    ) -> Result<CellBoundary> {
+        let (verts, adj_res) = self.verts(res);
+
+        // If we're returning the entire loop, we need one more iteration in
+        // case of a distortion vertex on the last edge
+        let additional_iteration = usize::from(length == NUM_PENT_VERTS);
+
+        // convert each vertex to lat/lng
+        // adjust the face of each vertex as appropriate and introduce
+        // edge-crossing vertices as needed
+        let mut boundary = CellBoundary::default();
+        let mut last_fijk = self.fijk.clone();
+        for vert in start..start + length + additional_iteration {
+            let v = vert % NUM_PENT_VERTS;
+
+            let mut fijk = verts[v].clone();
+            fijk.adjust_pent_vert_overage(adj_res);
+
+            // all Class III pentagon edges cross icosa edges
+            // note that Class II pentagons have vertices on the edge,
+            // not edge intersections
+            if is_resolution_class_iii(res) && vert > start {
+                // find hex2d of the two vertexes on the last face
+                let orig2d0 = Vec2d::from(&last_fijk.coord);
+
+                let orient = fijk
+                    .adjacent_dir(last_fijk.face)
+                    .and_then(|dir| fijk.neighbor(dir))
+                    .ok_or(H3ErrorCode::Domain)?;
+                let tmp = fijk.to_neighbor(orient, UNIT_SCALE_BY_CII_RES[adj_res] * 3);
+
+                let orig2d1 = Vec2d::from(&tmp.coord);
+
+                // find the appropriate icosa face edge vertexes
+                let (edge0, edge1) = icosa_edge(adj_res, tmp.adjacent_dir(fijk.face));
+
+                // find the intersection and add the lat/lng point to the result
+                let inter = Vec2d::intersect(&orig2d0, &orig2d1, &edge0, &edge1);
+                boundary.push(hex2d_to_geo(&inter, tmp.face, adj_res, true)?);
+            }
+
+            // convert vertex to lat/lng and add to the result
+            // vert == start + NUM_PENT_VERTS is only used to test for possible
+            // intersection on last edge
+            if vert < start + NUM_PENT_VERTS {
+                let vec = Vec2d::from(&fijk.coord);
+                boundary.push(hex2d_to_geo(&vec, fijk.face, adj_res, true)?);
+            }
+
+            last_fijk = fijk;
+        }
+
+        Ok(boundary)
+    }
+}
+
+/// Gets the first `N` vertices of a cell as substrate [`Face`] addresses,
+/// along with the adjusted (Class II) resolution of the substrate grid.
+fn substrate_verts<const N: usize>(fijk: &Face, res: usize) -> ([Face; N], usize) {
+    let verts = if is_resolution_class_iii(res) {
+        &VERTS_CIII
+    } else {
+        &VERTS_CII
+    };
+
+    // adjust the center point to be in an aperture 33r substrate grid
+    // these should be composed for speed
+    let mut center = fijk.coord.down_ap3().down_ap3r();
+    let mut adj_res = res;
+
+    // if res is Class III we need to add a cw aperture 7 to get to
+    // icosahedral Class II
+    if is_resolution_class_iii(res) {
+        center = center.down_ap7r();
+        adj_res += 1;
+    }
+
+    // The center point is now in the same substrate grid as the origin
+    // cell vertices. Add the center point substate coordinates
+    // to each vertex to translate the vertices to that cell.
+    let fijk_verts = core::array::from_fn(|v| Face {
+        face: fijk.face,
+        coord: (center + verts[v]).normalize(),
+    });
+
+    (fijk_verts, adj_res)
+}
+
+/// Finds the vertexes, in substrate hex2d coordinates of Class II resolution
+/// `res`, of the icosahedron face edge in the quadrant `dir`.
+fn icosa_edge(res: usize, dir: Option<usize>) -> (Vec2d, Vec2d) {
+    let max_dim = MAX_DIM_BY_CII_RES[res] as f64;
+    let v0 = Vec2d::new(3. * max_dim, 0.);
+    let v1 = Vec2d::new(-1.5 * max_dim, 3. * M_SQRT3_2 * max_dim);
+    let v2 = Vec2d::new(-1.5 * max_dim, -3. * M_SQRT3_2 * max_dim);
+
+    match dir {
+        Some(IJ) => (v0, v1),
+        Some(JK) => (v1, v2),
+        _ => {
+            debug_assert_eq!(Some(KI), dir);
+            (v2, v0)
+        }
+    }
+}
+
+/// Information to transform into an adjacent face IJK system.
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct FaceOrient {
+    /// face number
     pub face: isize,
+    /// res 0 translation relative to primary face
     pub translate: Coordinate,
+    /// number of 60 degree ccw rotations relative to primary face
     pub ccw_rot_60: isize,
 }
 
 /// Digit representing overage type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Overage {
     /// No overage (on original face)
     NoOverage,
@@ -26,3 +494,213 @@ pub enum Overage {
     /// Overage on new face interior
     NewFace,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        base_cell::NUM_BASE_CELLS, consts::M_PI, direction::Direction, geometry::Vec3d,
+        latlng::pos_angle_rads, NUM_ICOSA_FACES,
+    };
+
+    #[test]
+    fn face_ijk_shape_checked() {
+        let pentagon = H3Index::init(2, 4, Direction::Center);
+        let hexagon = H3Index::init(2, 4, Direction::JAxes);
+
+        assert!(FaceIJK::<Pentagon>::try_from(&pentagon).is_ok());
+        assert_eq!(
+            Err(H3ErrorCode::Pentagon),
+            FaceIJK::<Hexagon>::try_from(&pentagon)
+        );
+        assert!(FaceIJK::<Hexagon>::try_from(&hexagon).is_ok());
+        assert_eq!(
+            Err(H3ErrorCode::CellInvalid),
+            FaceIJK::<Pentagon>::try_from(&hexagon)
+        );
+    }
+
+    #[test]
+    fn face_ijk_to_h3() {
+        for res in 0..=3 {
+            let pentagon = H3Index::init(res, 14, Direction::Center);
+            let fijk = FaceIJK::<Pentagon>::try_from(&pentagon).unwrap();
+            assert_eq!(pentagon, fijk.to_h3(res).unwrap());
+
+            let hexagon = H3Index::init(res, 0, Direction::IJAxes);
+            let fijk = FaceIJK::<Hexagon>::try_from(&hexagon).unwrap();
+            assert_eq!(hexagon, fijk.to_h3(res).unwrap());
+        }
+    }
+
+    #[test]
+    fn face_ijk_verts() {
+        let hexagon = H3Index::init(1, 0, Direction::Center);
+        let (verts, adj_res) = FaceIJK::<Hexagon>::try_from(&hexagon).unwrap().verts(1);
+        assert_eq!(2, adj_res);
+        assert_eq!(Hexagon::NUM_VERTS, verts.len());
+
+        let pentagon = H3Index::init(2, 4, Direction::Center);
+        let (verts, adj_res) = FaceIJK::<Pentagon>::try_from(&pentagon).unwrap().verts(2);
+        assert_eq!(2, adj_res);
+        assert_eq!(Pentagon::NUM_VERTS, verts.len());
+    }
+
+    #[test]
+    fn face_neighbors_symmetric() {
+        for f in 0..NUM_ICOSA_FACES as isize {
+            let face = Face::new(f, Coordinate::default());
+            assert_eq!(f, face.neighbor(0).unwrap().face);
+            assert_eq!(Some(0), face.adjacent_dir(f));
+
+            for quadrant in [IJ, KI, JK] {
+                let orient = face.neighbor(quadrant).unwrap();
+                let back = Face::new(orient.face, Coordinate::default());
+
+                // the neighbor sees this face across exactly one quadrant,
+                // and the rotations in either direction cancel out
+                let back_quadrant = back.adjacent_dir(f).unwrap();
+                assert_ne!(0, back_quadrant);
+                assert_eq!(Some(quadrant), face.adjacent_dir(orient.face));
+                assert_eq!(f, back.neighbor(back_quadrant).unwrap().face);
+                assert_eq!(
+                    0,
+                    (orient.ccw_rot_60 + back.neighbor(back_quadrant).unwrap().ccw_rot_60) % 6
+                );
+            }
+
+            let adjacent = (0..NUM_ICOSA_FACES as isize)
+                .filter(|&other| face.adjacent_dir(other).is_some())
+                .count();
+            assert_eq!(4, adjacent);
+        }
+    }
+
+    #[test]
+    fn face_neighbors_invalid() {
+        let face = Face::new(0, Coordinate::default());
+        assert_eq!(None, face.neighbor(4));
+        assert_eq!(None, face.adjacent_dir(INVALID_FACE));
+        assert_eq!(None, face.adjacent_dir(NUM_ICOSA_FACES as isize));
+
+        for f in [INVALID_FACE, NUM_ICOSA_FACES as isize] {
+            let face = Face::new(f, Coordinate::default());
+            assert_eq!(None, face.neighbor(0));
+            assert_eq!(None, face.adjacent_dir(0));
+        }
+    }
+
+    #[test]
+    fn to_neighbor_round_trip() {
+        let res = 2;
+        let unit_scale = UNIT_SCALE_BY_CII_RES[res];
+
+        for f in 0..NUM_ICOSA_FACES as isize {
+            for quadrant in [IJ, KI, JK] {
+                let fijk = Face::new(f, Coordinate::new(3, 1, 0));
+                let moved = fijk.to_neighbor(fijk.neighbor(quadrant).unwrap(), unit_scale);
+                let back_quadrant = moved.adjacent_dir(f).unwrap();
+                assert_eq!(
+                    fijk,
+                    moved.to_neighbor(moved.neighbor(back_quadrant).unwrap(), unit_scale)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn face_centers_consistent() {
+        for f in 0..NUM_ICOSA_FACES {
+            let point = Vec3d::from(&FACE_CENTER_GEO[f]);
+            assert!(point.square_distance(&FACE_CENTER_POINT[f]) < 1e-12);
+
+            let axes = FACE_AXES_AZ_RADS_CII[f];
+            for (a, b) in [(0, 1), (1, 2), (2, 0)] {
+                let delta = pos_angle_rads(axes[a] - axes[b]);
+                assert!((delta - 2. * M_PI / 3.).abs() < 1e-9, "face {}", f);
+            }
+        }
+    }
+
+    #[test]
+    fn adjust_overage_class_ii() {
+        let res = 2;
+        let max_dim = MAX_DIM_BY_CII_RES[res];
+
+        // inside the face
+        let mut fijk = Face::new(1, Coordinate::new(max_dim, 0, 0));
+        assert_eq!(
+            Overage::NoOverage,
+            fijk.adjust_overage_class_ii(res, false, false)
+        );
+        assert_eq!(Face::new(1, Coordinate::new(max_dim, 0, 0)), fijk);
+
+        // on the edge of the substrate grid
+        let mut fijk = Face::new(1, Coordinate::new(3 * max_dim, 0, 0));
+        assert_eq!(
+            Overage::FaceEdge,
+            fijk.adjust_overage_class_ii(res, false, true)
+        );
+        assert_eq!(Face::new(1, Coordinate::new(3 * max_dim, 0, 0)), fijk);
+
+        // across each of the face edges
+        for (quadrant, coord) in [
+            (IJ, Coordinate::new(max_dim, 1, 0)),
+            (KI, Coordinate::new(max_dim, 0, 1)),
+            (JK, Coordinate::new(0, max_dim, 1)),
+        ] {
+            for f in 0..NUM_ICOSA_FACES as isize {
+                let mut fijk = Face::new(f, coord);
+                let expected = fijk.neighbor(quadrant).unwrap().face;
+                assert_eq!(
+                    Overage::NewFace,
+                    fijk.adjust_overage_class_ii(res, false, false)
+                );
+                assert_eq!(expected, fijk.face);
+                assert!(fijk.coord.i + fijk.coord.j + fijk.coord.k <= max_dim);
+            }
+        }
+    }
+
+    #[test]
+    fn adjust_overage_class_ii_pent_leading_4() {
+        let res = 2;
+        let max_dim = MAX_DIM_BY_CII_RES[res];
+        let coord = Coordinate::new(max_dim, 0, 1);
+
+        let mut plain = Face::new(0, coord);
+        let mut pent = Face::new(0, coord);
+        assert_eq!(
+            Overage::NewFace,
+            plain.adjust_overage_class_ii(res, false, false)
+        );
+        assert_eq!(
+            Overage::NewFace,
+            pent.adjust_overage_class_ii(res, true, false)
+        );
+
+        // both move into the ki quadrant of face 0, which is face 1, but the
+        // missing sequence rotates the address cw about the pentagon first
+        assert_eq!(Face::new(1, Coordinate::new(13, 0, 0)), plain);
+        assert_eq!(Face::new(1, Coordinate::new(14, 1, 0)), pent);
+    }
+
+    #[test]
+    fn adjust_pent_vert_overage() {
+        for bc in 0..NUM_BASE_CELLS {
+            let h = H3Index::init(1, bc as u64, Direction::Center);
+            if !h.is_pentagon() {
+                continue;
+            }
+
+            let pentagon = FaceIJK::<Pentagon>::try_from(&h).unwrap();
+            let (verts, adj_res) = pentagon.verts(1);
+            for mut vert in verts {
+                let overage = vert.adjust_pent_vert_overage(adj_res);
+                assert_ne!(Overage::NewFace, overage);
+                let sum = vert.coord.i + vert.coord.j + vert.coord.k;
+                assert!(sum <= 3 * MAX_DIM_BY_CII_RES[adj_res]);
+            }
+        }
+    }
+}
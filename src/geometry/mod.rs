@@ -0,0 +1,359 @@
+//! Planar and spatial vector types, and the face-centered gnomonic
+//! projections between spherical coordinates and the hex2d coordinate
+//! systems used by the indexer.
+use derive_new::new;
+
+use crate::{
+    consts::{
+        EPSILON, M_AP7_ROT_RADS, M_ONETHIRD, M_RSIN60, M_RSQRT7, M_SQRT3_2, M_SQRT7,
+        RES0_U_GNOMONIC,
+    },
+    coordinate::Coordinate,
+    face::{FACE_AXES_AZ_RADS_CII, FACE_CENTER_GEO, FACE_CENTER_POINT},
+    is_resolution_class_iii,
+    latlng::{pos_angle_rads, LatLng},
+    result::{H3ErrorCode, Result},
+    NUM_ICOSA_FACES,
+};
+
+/// 2D floating-point vector.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, new)]
+pub struct Vec2d {
+    /// x component
+    pub x: f64,
+    /// y component
+    pub y: f64,
+}
+
+/// 3D floating point structure.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, new)]
+pub struct Vec3d {
+    /// x component
+    pub x: f64,
+    /// y component
+    pub y: f64,
+    /// z component
+    pub z: f64,
+}
+
+impl Vec2d {
+    /// Calculates the magnitude of the vector.
+    pub fn magnitude(&self) -> f64 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+
+    /// Finds the intersection between the line through `p0` and `p1` and the
+    /// line through `p2` and `p3`. Assumes that the lines intersect and that
+    /// the intersection is not at an endpoint of either line.
+    pub fn intersect(p0: &Vec2d, p1: &Vec2d, p2: &Vec2d, p3: &Vec2d) -> Vec2d {
+        let s1 = Vec2d::new(p1.x - p0.x, p1.y - p0.y);
+        let s2 = Vec2d::new(p3.x - p2.x, p3.y - p2.y);
+
+        // single precision, as in the reference implementation
+        let t = ((s2.x * (p0.y - p2.y) - s2.y * (p0.x - p2.x)) / (-s2.x * s1.y + s1.x * s2.y))
+            as f32 as f64;
+
+        Vec2d::new(p0.x + t * s1.x, p0.y + t * s1.y)
+    }
+
+    /// Whether two 2D vectors are almost equal, within some threshold.
+    pub fn almost_equals(&self, other: &Vec2d) -> bool {
+        ((self.x - other.x).abs() as f32) < f32::EPSILON
+            && ((self.y - other.y).abs() as f32) < f32::EPSILON
+    }
+}
+
+impl From<&Vec2d> for Coordinate {
+    /// Determines the containing hex in ijk+ coordinates for a 2D cartesian
+    /// coordinate vector (from DGGRID).
+    fn from(v: &Vec2d) -> Self {
+        // quantize into the ij system and then normalize
+        let a1 = v.x.abs();
+        let a2 = v.y.abs();
+
+        // first do a reverse conversion
+        let x2 = a2 * M_RSIN60;
+        let x1 = a1 + x2 / 2.;
+
+        // check if we have the center of a hex
+        let m1 = x1 as isize;
+        let m2 = x2 as isize;
+
+        // otherwise round correctly
+        let r1 = x1 - m1 as f64;
+        let r2 = x2 - m2 as f64;
+
+        let (mut i, mut j) = if r1 < 0.5 {
+            if r1 < 1. / 3. {
+                if r2 < (1. + r1) / 2. {
+                    (m1, m2)
+                } else {
+                    (m1, m2 + 1)
+                }
+            } else {
+                let j = if r2 < (1. - r1) { m2 } else { m2 + 1 };
+                let i = if (1. - r1) <= r2 && r2 < (2. * r1) {
+                    m1 + 1
+                } else {
+                    m1
+                };
+
+                (i, j)
+            }
+        } else if r1 < 2. / 3. {
+            let j = if r2 < (1. - r1) { m2 } else { m2 + 1 };
+            let i = if (2. * r1 - 1.) < r2 && r2 < (1. - r1) {
+                m1
+            } else {
+                m1 + 1
+            };
+
+            (i, j)
+        } else if r2 < (r1 / 2.) {
+            (m1 + 1, m2)
+        } else {
+            (m1 + 1, m2 + 1)
+        };
+
+        // now fold across the axes if necessary
+        if v.x < 0. {
+            if j % 2 == 0 {
+                // even
+                let axis_i = j / 2;
+                let diff = i - axis_i;
+                i -= 2 * diff;
+            } else {
+                let axis_i = (j + 1) / 2;
+                let diff = i - axis_i;
+                i -= 2 * diff + 1;
+            }
+        }
+
+        if v.y < 0. {
+            i -= (2 * j + 1) / 2;
+            j = -j;
+        }
+
+        Coordinate::new(i, j, 0).normalize()
+    }
+}
+
+impl From<&Coordinate> for Vec2d {
+    /// Finds the center point in 2D cartesian coordinates of a hex.
+    fn from(h: &Coordinate) -> Self {
+        let i = (h.i - h.k) as f64;
+        let j = (h.j - h.k) as f64;
+
+        Self::new(i - 0.5 * j, j * M_SQRT3_2)
+    }
+}
+
+impl Vec3d {
+    /// Calculates the square of the distance between two 3D coordinates.
+    pub fn square_distance(&self, other: &Vec3d) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+impl From<&LatLng> for Vec3d {
+    /// Calculates the 3D coordinate on unit sphere from the latitude and longitude.
+    fn from(geo: &LatLng) -> Self {
+        let r = geo.lat.cos();
+
+        Self::new(geo.lng.cos() * r, geo.lng.sin() * r, geo.lat.sin())
+    }
+}
+
+/// Finds the icosahedron face whose center is closest to `g`, along with the
+/// squared euclidean distance between `g` and that face center.
+pub fn closest_face(g: &LatLng) -> (isize, f64) {
+    let v3d = Vec3d::from(g);
+
+    // determine the icosahedron face; the maximum possible squared
+    // distance between two points on the unit sphere is 4.0
+    (0..NUM_ICOSA_FACES).fold((0, 5.), |(face, sqd), f| {
+        let sqd_t = FACE_CENTER_POINT[f].square_distance(&v3d);
+        if sqd_t < sqd {
+            (f as isize, sqd_t)
+        } else {
+            (face, sqd)
+        }
+    })
+}
+
+/// Encodes a coordinate on the sphere to the corresponding icosahedral face
+/// and containing 2D hex coordinates relative to that face center.
+///
+/// The point is projected onto the closest face using a gnomonic projection
+/// scaled by [`RES0_U_GNOMONIC`], then scaled by [`M_SQRT7`] for each
+/// resolution below `res` and rotated onto the Class III axes for odd
+/// resolutions.
+pub fn geo_to_hex2d(g: &LatLng, res: usize) -> (isize, Vec2d) {
+    // determine the icosahedron face
+    let (face, sqd) = closest_face(g);
+
+    // cos(r) = 1 - 2 * sin^2(r/2) = 1 - 2 * (sqd / 4) = 1 - sqd/2
+    let r = (1. - sqd / 2.).acos();
+
+    if r < EPSILON {
+        return (face, Vec2d::new(0., 0.));
+    }
+
+    // now have face and r, now find CCW theta from CII i-axis
+    let f = face as usize;
+    let mut theta = pos_angle_rads(
+        FACE_AXES_AZ_RADS_CII[f][0] - pos_angle_rads(FACE_CENTER_GEO[f].azimuth_rads(g)),
+    );
+
+    // adjust theta for Class III (odd resolutions)
+    if is_resolution_class_iii(res) {
+        theta = pos_angle_rads(theta - M_AP7_ROT_RADS);
+    }
+
+    // perform gnomonic scaling of r
+    let mut r = r.tan() / RES0_U_GNOMONIC;
+
+    // scale for current resolution length u
+    for _ in 0..res {
+        r *= M_SQRT7;
+    }
+
+    // we now have (r, theta) in hex2d with theta ccw from x-axes
+    (face, Vec2d::new(r * theta.cos(), r * theta.sin()))
+}
+
+/// Determines the center point in spherical coordinates of a cell given by 2D
+/// hex coordinates on a particular icosahedral face. If `substrate` is set,
+/// `v` is taken to be on the aperture 3 substrate grid of `res`.
+///
+/// This is the inverse of [`geo_to_hex2d`].
+///
+/// Fails with [`H3ErrorCode::Domain`] if `face` is not an icosahedron face.
+pub fn hex2d_to_geo(v: &Vec2d, face: isize, res: usize, substrate: bool) -> Result<LatLng> {
+    let f = usize::try_from(face)
+        .ok()
+        .filter(|&f| f < NUM_ICOSA_FACES)
+        .ok_or(H3ErrorCode::Domain)?;
+
+    // calculate (r, theta) in hex2d
+    let mut r = v.magnitude();
+
+    if r < EPSILON {
+        return Ok(FACE_CENTER_GEO[f]);
+    }
+
+    let mut theta = v.y.atan2(v.x);
+
+    // scale for current resolution length u
+    for _ in 0..res {
+        r *= M_RSQRT7;
+    }
+
+    // scale accordingly if this is a substrate grid
+    if substrate {
+        r *= M_ONETHIRD;
+        if is_resolution_class_iii(res) {
+            r *= M_RSQRT7;
+        }
+    }
+
+    r *= RES0_U_GNOMONIC;
+
+    // perform inverse gnomonic scaling of r
+    r = r.atan();
+
+    // adjust theta for Class III
+    // if a substrate grid, then it's already been adjusted for Class III
+    if !substrate && is_resolution_class_iii(res) {
+        theta = pos_angle_rads(theta + M_AP7_ROT_RADS);
+    }
+
+    // find theta as an azimuth
+    theta = pos_angle_rads(FACE_AXES_AZ_RADS_CII[f][0] - theta);
+
+    // now find the point at (r,theta) from the face center
+    Ok(FACE_CENTER_GEO[f].az_distance_rads(theta, r))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{consts::M_SQRT3_2, latlng::LatLng};
+
+    #[test]
+    fn vec2d_magnitude() {
+        assert_eq!(5., Vec2d::new(3., -4.).magnitude());
+        assert_eq!(0., Vec2d::new(0., 0.).magnitude());
+    }
+
+    #[test]
+    fn vec2d_intersect() {
+        let inter = Vec2d::intersect(
+            &Vec2d::new(2., 2.),
+            &Vec2d::new(6., 6.),
+            &Vec2d::new(0., 4.),
+            &Vec2d::new(10., 4.),
+        );
+        assert!(inter.almost_equals(&Vec2d::new(4., 4.)));
+    }
+
+    #[test]
+    fn vec2d_ijk_round_trip() {
+        for i in 0..5 {
+            for j in 0..5 {
+                let ijk = Coordinate::new(i, j, 0).normalize();
+                assert_eq!(ijk, Coordinate::from(&Vec2d::from(&ijk)));
+            }
+        }
+
+        assert_eq!(
+            Vec2d::new(0.5, M_SQRT3_2),
+            Vec2d::from(&Coordinate::new(1, 1, 0))
+        );
+    }
+
+    #[test]
+    fn vec3d_square_distance() {
+        let north = Vec3d::from(&LatLng::from_degrees(90., 0.));
+        let south = Vec3d::from(&LatLng::from_degrees(-90., 0.));
+        assert!((north.square_distance(&south) - 4.).abs() < EPSILON);
+        assert!(north.square_distance(&north) < EPSILON);
+    }
+
+    #[test]
+    fn closest_face_centers() {
+        for (face, center) in FACE_CENTER_GEO.iter().enumerate() {
+            let (closest, sqd) = closest_face(center);
+            assert_eq!(face as isize, closest);
+            assert!(sqd < 1e-12);
+        }
+    }
+
+    #[test]
+    fn hex2d_round_trip() {
+        let g = LatLng::from_degrees(37.3615593, -122.0553238);
+
+        for res in 0..=15 {
+            let (face, v) = geo_to_hex2d(&g, res);
+            let back = hex2d_to_geo(&v, face, res, false).unwrap();
+            assert!((back.lat - g.lat).abs() < 1e-12, "res {}", res);
+            assert!((back.lng - g.lng).abs() < 1e-12, "res {}", res);
+        }
+    }
+
+    #[test]
+    fn hex2d_to_geo_invalid_face() {
+        let v = Vec2d::new(1., 1.);
+        for face in [-1, NUM_ICOSA_FACES as isize] {
+            assert_eq!(Err(H3ErrorCode::Domain), hex2d_to_geo(&v, face, 5, false));
+            assert_eq!(
+                Err(H3ErrorCode::Domain),
+                hex2d_to_geo(&Vec2d::new(0., 0.), face, 5, true)
+            );
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Directed edges between neighboring cells. A directed edge index is the
+//! index of its origin cell in [`H3_DIRECTEDEDGE_MODE`], with the direction to
+//! the destination cell stored in the reserved bits.
+use super::H3Index;
+use crate::{
+    cell_boundary::CellBoundary,
+    consts::{H3_CELL_MODE, H3_DIRECTEDEDGE_MODE},
+    direction::Direction,
+    face::{FaceIJK, Hexagon, Pentagon},
+    result::{H3ErrorCode, Result},
+};
+
+impl H3Index {
+    /// Produces the directed edge from `self` to the neighboring cell
+    /// `destination`.
+    ///
+    /// Fails with [`H3ErrorCode::CellInvalid`] if either index is not a valid
+    /// cell, [`H3ErrorCode::ResMismatch`] if the cells are of different
+    /// resolutions, and [`H3ErrorCode::NotNeighbors`] if the cells are not
+    /// neighbors.
+    pub fn cells_to_directed_edge(&self, destination: &H3Index) -> Result<H3Index> {
+        if !self.valid_cell() || !destination.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+        if self.resolution() != destination.resolution() {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        // determine the ijk direction from the origin to the destination
+        let dir = self
+            .direction_for_neighbor(destination)
+            .ok_or(H3ErrorCode::NotNeighbors)?;
+
+        Ok(self
+            .clone()
+            .set_mode(H3_DIRECTEDEDGE_MODE)
+            .set_reserved(usize::from(dir)))
+    }
+
+    /// Returns whether the index is a valid directed edge.
+    pub fn is_valid_directed_edge(&self) -> bool {
+        self.origin().is_ok()
+    }
+
+    /// Returns the origin cell of the directed edge.
+    ///
+    /// Fails with [`H3ErrorCode::DirEdgeInvalid`] if the index is not a valid
+    /// directed edge: it is not in directed edge mode, its origin is not a
+    /// valid cell, or its direction does not lead to a neighbor.
+    pub fn origin(&self) -> Result<H3Index> {
+        if self.mode() != H3_DIRECTEDEDGE_MODE {
+            return Err(H3ErrorCode::DirEdgeInvalid);
+        }
+
+        let origin = self.clone().set_mode(H3_CELL_MODE).set_reserved(0);
+
+        // the center is the origin itself, and pentagons have no neighbor in
+        // the deleted k direction
+        let dir = self.edge_direction();
+        if dir == Direction::Center
+            || dir == Direction::Invalid
+            || !origin.valid_cell()
+            || (origin.is_pentagon() && dir == Direction::KAxes)
+        {
+            return Err(H3ErrorCode::DirEdgeInvalid);
+        }
+
+        Ok(origin)
+    }
+
+    /// Returns the destination cell of the directed edge.
+    ///
+    /// Fails with [`H3ErrorCode::DirEdgeInvalid`] if the index is not a valid
+    /// directed edge.
+    pub fn destination(&self) -> Result<H3Index> {
+        let (destination, _) = self
+            .origin()?
+            .neighbor_rotations(self.edge_direction(), 0)?;
+
+        Ok(destination)
+    }
+
+    /// Returns the origin and destination cells of the directed edge, failing
+    /// as [`H3Index::destination`] does.
+    pub fn directed_edge_to_cells(&self) -> Result<(H3Index, H3Index)> {
+        Ok((self.origin()?, self.destination()?))
+    }
+
+    /// Produces all the directed edges from `self` to its neighbors: six for
+    /// a hexagon, and five for a pentagon, which has no edge in the deleted k
+    /// direction.
+    pub fn origin_to_directed_edges(&self) -> Result<Vec<H3Index>> {
+        if !self.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        let is_pentagon = self.is_pentagon();
+        Ok(
+            (usize::from(Direction::KAxes)..=usize::from(Direction::IJAxes))
+                .filter(|&dir| !(is_pentagon && Direction::from(dir) == Direction::KAxes))
+                .map(|dir| {
+                    self.clone()
+                        .set_mode(H3_DIRECTEDEDGE_MODE)
+                        .set_reserved(dir)
+                })
+                .collect(),
+        )
+    }
+
+    /// Finds the boundary of the directed edge, from the origin's vertex to
+    /// the destination's. While there are always two topological vertices
+    /// per edge, the boundary has an additional distortion vertex if the
+    /// edge crosses an edge of the icosahedron.
+    ///
+    /// Fails with [`H3ErrorCode::DirEdgeInvalid`] if the index is not a
+    /// directed edge.
+    pub fn directed_edge_to_boundary(&self) -> Result<CellBoundary> {
+        let origin = self.origin()?;
+
+        // get the start vertex for the edge
+        let start = origin
+            .vertex_num_for_direction(self.edge_direction())
+            .map_err(|_| H3ErrorCode::DirEdgeInvalid)?;
+
+        // get the boundary for the appropriate vertices of the origin
+        let res = origin.resolution();
+        if origin.is_pentagon() {
+            FaceIJK::<Pentagon>::try_from(&origin)?.to_cell_boundary(res, start, 2)
+        } else {
+            FaceIJK::<Hexagon>::try_from(&origin)?.to_cell_boundary(res, start, 2)
+        }
+    }
+
+    /// Returns the direction from the origin to the destination of the
+    /// directed edge, stored in the reserved bits.
+    fn edge_direction(&self) -> Direction {
+        Direction::from(self.reserved())
+    }
+}
@@ -0,0 +1,383 @@
+use std::collections::{HashMap, HashSet};
+
+use super::{H3Index, H3_PER_DIGIT_OFFSET};
+use crate::{
+    direction::Direction,
+    result::{H3ErrorCode, Result},
+    MAX_H3_RES,
+};
+
+/// Lazy iterator over the children of a cell at a given resolution, produced
+/// by [`H3Index::children`].
+///
+/// Children are produced in increasing index order. The children of a
+/// pentagon skip the deleted k subsequence.
+#[derive(Clone, Debug)]
+pub struct Children {
+    /// next child to produce, or `None` when exhausted
+    h: Option<H3Index>,
+    /// resolution of the parent cell
+    parent_res: usize,
+    /// finest resolution at which a leading k digit must be skipped; only
+    /// set for the children of pentagons
+    skip_digit: Option<usize>,
+}
+
+impl Children {
+    /// Adds one to the digit at resolution `res` of the current child,
+    /// carrying into coarser digits.
+    fn increment_digit(h: &mut H3Index, res: usize) {
+        h.0 += 1 << (H3_PER_DIGIT_OFFSET * (MAX_H3_RES - res as u64));
+    }
+}
+
+impl Iterator for Children {
+    type Item = H3Index;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.h.clone()?;
+
+        let mut h = current.clone();
+        let child_res = h.resolution();
+        Self::increment_digit(&mut h, child_res);
+
+        for r in (self.parent_res..=child_res).rev() {
+            if r == self.parent_res {
+                // carried into the parent; no more children
+                self.h = None;
+                return Some(current);
+            }
+
+            if self.skip_digit == Some(r) && h.index_digit(r) == Direction::KAxes {
+                // all children of a pentagon have the property that the first
+                // nonzero digit between the parent and child resolutions is
+                // not 1, i.e. we never see a sequence like 00001, so skip the
+                // 1 in this digit
+                Self::increment_digit(&mut h, r);
+                self.skip_digit = r.checked_sub(1);
+                break;
+            }
+
+            if h.index_digit(r) == Direction::Invalid {
+                // zeros out this digit and increments the next coarser one
+                Self::increment_digit(&mut h, r);
+            } else {
+                break;
+            }
+        }
+
+        self.h = Some(h);
+        Some(current)
+    }
+}
+
+/// Compacts a set of cells of the same resolution into the smallest set of
+/// mixed resolution cells covering the same area, by replacing every complete
+/// set of siblings with their parent, repeatedly.
+///
+/// Fails with [`H3ErrorCode::ResMismatch`] if the cells are not all of the same
+/// resolution, and [`H3ErrorCode::DuplicateInput`] if a cell appears more than
+/// once.
+pub fn compact_cells(cells: &[H3Index]) -> Result<Vec<H3Index>> {
+    let Some(first) = cells.first() else {
+        return Ok(Vec::new());
+    };
+
+    let res = first.resolution();
+    let mut seen = HashSet::with_capacity(cells.len());
+    for cell in cells {
+        if cell.resolution() != res {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        if !seen.insert(cell) {
+            return Err(H3ErrorCode::DuplicateInput);
+        }
+    }
+
+    let mut compacted = Vec::new();
+    let mut remaining = cells.to_vec();
+    for child_res in (1..=res).rev() {
+        let mut counts = HashMap::new();
+        for cell in &remaining {
+            *counts.entry(cell.parent(child_res - 1)?).or_insert(0) += 1;
+        }
+
+        let mut parents = Vec::new();
+        for cell in remaining {
+            let parent = cell.parent(child_res - 1)?;
+            match counts.get(&parent) {
+                Some(&count) if count == parent.children_count(child_res)? => {
+                    // all the siblings are present; emit the parent once and
+                    // try to compact it further at the next resolution
+                    counts.remove(&parent);
+                    parents.push(parent);
+                }
+                Some(_) => compacted.push(cell),
+                // the parent has already been emitted
+                None => {}
+            }
+        }
+
+        if parents.is_empty() {
+            return Ok(compacted);
+        }
+
+        remaining = parents;
+    }
+
+    compacted.extend(remaining);
+    Ok(compacted)
+}
+
+/// Expands a set of mixed resolution cells, such as produced by
+/// [`compact_cells`], into the set of their children at resolution `res`.
+///
+/// The cells are validated up front, but the children are produced lazily:
+/// uncompacting even a single coarse cell can yield trillions of cells, so
+/// callers that need a buffer should check [`uncompact_cells_size`] first.
+///
+/// Fails with [`H3ErrorCode::ResDomain`] if `res` is not a valid resolution,
+/// and [`H3ErrorCode::ResMismatch`] if any cell is finer than `res`.
+pub fn uncompact_cells(
+    cells: &[H3Index],
+    res: usize,
+) -> Result<impl Iterator<Item = H3Index> + '_> {
+    uncompact_cells_size(cells, res)?;
+
+    // every cell was checked above, so producing the children cannot fail
+    Ok(cells
+        .iter()
+        .flat_map(move |cell| cell.children(res).into_iter().flatten()))
+}
+
+/// Returns the number of cells [`uncompact_cells`] produces for the given
+/// cells and resolution, failing with the same errors.
+pub fn uncompact_cells_size(cells: &[H3Index], res: usize) -> Result<i64> {
+    if res > MAX_H3_RES as usize {
+        return Err(H3ErrorCode::ResDomain);
+    }
+
+    cells.iter().try_fold(0, |size, cell| {
+        if cell.resolution() > res {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        Ok(size + cell.children_count(res)?)
+    })
+}
+
+impl H3Index {
+    /// Produces the parent of the cell at resolution `res`.
+    ///
+    /// Fails with [`H3ErrorCode::ResDomain`] if `res` is not a valid
+    /// resolution, and [`H3ErrorCode::ResMismatch`] if it is finer than the
+    /// resolution of the cell.
+    pub fn parent(&self, res: usize) -> Result<H3Index> {
+        let child_res = self.resolution();
+        if res > MAX_H3_RES as usize {
+            return Err(H3ErrorCode::ResDomain);
+        }
+
+        if res > child_res {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        Ok(
+            (res + 1..=child_res).fold(self.clone().set_resolution(res), |h3, r| {
+                h3.set_index_digit(r, Direction::Invalid)
+            }),
+        )
+    }
+
+    /// Produces a lazy iterator over the children of the cell at resolution
+    /// `res`.
+    ///
+    /// Fails with [`H3ErrorCode::ResDomain`] if `res` is not a valid
+    /// resolution at least as fine as the resolution of the cell.
+    pub fn children(&self, res: usize) -> Result<Children> {
+        let parent_res = self.resolution();
+        let h = self.center_child(res)?;
+        let skip_digit = h.is_pentagon().then_some(res);
+
+        Ok(Children {
+            h: Some(h),
+            parent_res,
+            skip_digit,
+        })
+    }
+
+    /// Returns the number of children of the cell at resolution `res`.
+    ///
+    /// Fails with [`H3ErrorCode::ResDomain`] if `res` is not a valid
+    /// resolution at least as fine as the resolution of the cell.
+    pub fn children_count(&self, res: usize) -> Result<i64> {
+        let parent_res = self.resolution();
+        if !self.has_child_at_res(res) {
+            return Err(H3ErrorCode::ResDomain);
+        }
+
+        let n = 7i64.pow((res - parent_res) as u32);
+        if self.is_pentagon() {
+            // a pentagon has one pentagonal center child and five hexagonal
+            // children at each finer resolution
+            Ok(1 + 5 * (n - 1) / 6)
+        } else {
+            Ok(n)
+        }
+    }
+
+    /// Produces the center child of the cell at resolution `res`.
+    ///
+    /// Fails with [`H3ErrorCode::ResDomain`] if `res` is not a valid
+    /// resolution at least as fine as the resolution of the cell.
+    pub fn center_child(&self, res: usize) -> Result<H3Index> {
+        if !self.has_child_at_res(res) {
+            return Err(H3ErrorCode::ResDomain);
+        }
+
+        Ok(
+            (self.resolution() + 1..=res).fold(self.clone().set_resolution(res), |h3, r| {
+                h3.set_index_digit(r, Direction::Center)
+            }),
+        )
+    }
+
+    /// Produces the other children of the cell's parent at the cell's
+    /// resolution.
+    ///
+    /// Fails with [`H3ErrorCode::ResDomain`] for resolution 0 cells, which
+    /// have no parent.
+    pub fn siblings(&self) -> Result<Vec<H3Index>> {
+        let res = self.resolution();
+        if res == 0 {
+            return Err(H3ErrorCode::ResDomain);
+        }
+
+        Ok(self
+            .parent(res - 1)?
+            .children(res)?
+            .filter(|sibling| sibling != self)
+            .collect())
+    }
+
+    /// Returns the position of the cell within an ordered list of all the
+    /// children of its parent at resolution `parent_res`, as produced by
+    /// [`H3Index::children`].
+    ///
+    /// Fails with [`H3ErrorCode::ResDomain`] or [`H3ErrorCode::ResMismatch`]
+    /// as [`H3Index::parent`] does.
+    pub fn cell_to_child_pos(&self, parent_res: usize) -> Result<i64> {
+        let child_res = self.resolution();
+        let parent = self.parent(parent_res)?;
+
+        let mut pos = 0;
+        if parent.is_pentagon() {
+            // pentagon parents skip the 1 digit, so the offsets are different
+            for res in (parent_res + 1..=child_res).rev() {
+                let parent_is_pentagon = self.parent(res - 1)?.is_pentagon();
+                let raw_digit = self.index_digit(res);
+
+                // validate the digit before proceeding
+                if raw_digit == Direction::Invalid
+                    || (parent_is_pentagon && raw_digit == Direction::KAxes)
+                {
+                    return Err(H3ErrorCode::CellInvalid);
+                }
+
+                let mut digit = usize::from(raw_digit) as i64;
+                if parent_is_pentagon && digit > 0 {
+                    digit -= 1;
+                }
+
+                if digit != 0 {
+                    let hex_child_count = 7i64.pow((child_res - res) as u32);
+
+                    // the offset for the 0 digit slot depends on whether the
+                    // current index is the child of a pentagon; if so, the
+                    // offset is based on the count of pentagon children,
+                    // otherwise hexagon children
+                    pos += if parent_is_pentagon {
+                        1 + 5 * (hex_child_count - 1) / 6
+                    } else {
+                        hex_child_count
+                    } + (digit - 1) * hex_child_count;
+                }
+            }
+        } else {
+            // offsets are simple powers of 7
+            for res in (parent_res + 1..=child_res).rev() {
+                let digit = self.index_digit(res);
+                if digit == Direction::Invalid {
+                    return Err(H3ErrorCode::CellInvalid);
+                }
+
+                pos += usize::from(digit) as i64 * 7i64.pow((child_res - res) as u32);
+            }
+        }
+
+        Ok(pos)
+    }
+
+    /// Produces the child of the cell at resolution `child_res` at position
+    /// `child_pos` within the ordered list of its children.
+    ///
+    /// This is the inverse of [`H3Index::cell_to_child_pos`]. Fails with
+    /// [`H3ErrorCode::ResDomain`] if `child_res` is not a valid resolution,
+    /// [`H3ErrorCode::ResMismatch`] if it is coarser than the resolution of
+    /// the cell, and [`H3ErrorCode::Domain`] if `child_pos` is out of range.
+    pub fn child_pos_to_cell(&self, child_pos: i64, child_res: usize) -> Result<H3Index> {
+        let parent_res = self.resolution();
+        if child_res > MAX_H3_RES as usize {
+            return Err(H3ErrorCode::ResDomain);
+        }
+
+        if child_res < parent_res {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        if child_pos < 0 || child_pos >= self.children_count(child_res)? {
+            return Err(H3ErrorCode::Domain);
+        }
+
+        let res_offset = child_res - parent_res;
+        let mut child = self.clone().set_resolution(child_res);
+        let mut idx = child_pos;
+
+        // pentagon parents skip the 1 digit, so the offsets are different
+        // while we are still inside the pentagon
+        let mut in_pent = self.is_pentagon();
+        for res in 1..=res_offset {
+            let res_width = 7i64.pow((res_offset - res) as u32);
+
+            let digit = if in_pent {
+                // while we are inside a parent pentagon, we need to check if
+                // this cell is a pentagon, and if not, we need to offset its
+                // digit to account for the skipped direction
+                let pent_width = 1 + 5 * (res_width - 1) / 6;
+                if idx < pent_width {
+                    0
+                } else {
+                    idx -= pent_width;
+                    in_pent = false;
+                    let digit = idx / res_width + 2;
+                    idx %= res_width;
+                    digit
+                }
+            } else {
+                let digit = idx / res_width;
+                idx %= res_width;
+                digit
+            };
+
+            child = child.set_index_digit(parent_res + res, digit as u64);
+        }
+
+        Ok(child)
+    }
+
+    /// Returns whether the cell has children at resolution `res`.
+    fn has_child_at_res(&self, res: usize) -> bool {
+        res >= self.resolution() && res <= MAX_H3_RES as usize
+    }
+}
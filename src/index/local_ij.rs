@@ -0,0 +1,475 @@
+//! Local IJ coordinates.
+//!
+//! Algorithms working with hexagons may want to refer to grid coordinates
+//! that are not interrupted by base cells or faces. These coordinates have 2
+//! coordinate axes spaced 120 degrees apart, with the coordinates anchored by
+//! an origin cell.
+//!
+//! - local coordinates are only comparable when they have the same origin
+//!   cell.
+//! - local coordinates are only valid near the origin. Practically, this is
+//!   within the same base cell or a neighboring base cell, except for
+//!   pentagons.
+//! - the coordinate space may have deleted or warped regions due to pentagon
+//!   distortion.
+//! - there may be multiple coordinates for the same cell, with the same
+//!   origin.
+//! - the origin may not be at (0, 0) in the local coordinate space.
+use super::{H3Index, H3_INIT};
+use crate::{
+    base_cell::BaseCell,
+    consts::H3_CELL_MODE,
+    coordinate::{CoordIJ, Coordinate},
+    direction::Direction,
+    face::Face,
+    is_resolution_class_iii,
+    result::{H3ErrorCode, Result},
+};
+
+/// Origin leading digit -> index leading digit -> rotations 60 cw.
+///
+/// Either being 1 (K axis) is invalid. No good default at 0.
+#[rustfmt::skip]
+const PENTAGON_ROTATIONS: [[isize; 7]; 7] = [
+    [ 0, -1,  0,  0,  0,  0,  0], // 0
+    [-1, -1, -1, -1, -1, -1, -1], // 1
+    [ 0, -1,  0,  0,  0,  1,  0], // 2
+    [ 0, -1,  0,  0,  1,  1,  0], // 3
+    [ 0, -1,  0,  5,  0,  0,  0], // 4
+    [ 0, -1,  5,  5,  0,  0,  0], // 5
+    [ 0, -1,  0,  0,  0,  0,  0], // 6
+];
+
+/// Reverse base cell direction -> leading index digit -> rotations 60 ccw.
+///
+/// For reversing the rotation introduced in [`PENTAGON_ROTATIONS`] when the
+/// origin is on a pentagon (regardless of the base cell of the index).
+#[rustfmt::skip]
+const PENTAGON_ROTATIONS_REVERSE: [[isize; 7]; 7] = [
+    [ 0,  0,  0,  0,  0,  0,  0], // 0
+    [-1, -1, -1, -1, -1, -1, -1], // 1
+    [ 0,  1,  0,  0,  0,  0,  0], // 2
+    [ 0,  1,  0,  0,  0,  1,  0], // 3
+    [ 0,  5,  0,  0,  0,  0,  0], // 4
+    [ 0,  5,  0,  5,  0,  0,  0], // 5
+    [ 0,  0,  0,  0,  0,  0,  0], // 6
+];
+
+/// Reverse base cell direction -> leading index digit -> rotations 60 ccw.
+///
+/// For reversing the rotation introduced in [`PENTAGON_ROTATIONS`] when the
+/// index is on a pentagon and the origin is not.
+#[rustfmt::skip]
+const PENTAGON_ROTATIONS_REVERSE_NONPOLAR: [[isize; 7]; 7] = [
+    [ 0,  0,  0,  0,  0,  0,  0], // 0
+    [-1, -1, -1, -1, -1, -1, -1], // 1
+    [ 0,  1,  0,  0,  0,  0,  0], // 2
+    [ 0,  1,  0,  0,  0,  1,  0], // 3
+    [ 0,  5,  0,  0,  0,  0,  0], // 4
+    [ 0,  1,  0,  5,  1,  1,  0], // 5
+    [ 0,  0,  0,  0,  0,  0,  0], // 6
+];
+
+/// Reverse base cell direction -> leading index digit -> rotations 60 ccw.
+///
+/// For reversing the rotation introduced in [`PENTAGON_ROTATIONS`] when the
+/// index is on a polar pentagon and the origin is not.
+#[rustfmt::skip]
+const PENTAGON_ROTATIONS_REVERSE_POLAR: [[isize; 7]; 7] = [
+    [ 0,  0,  0,  0,  0,  0,  0], // 0
+    [-1, -1, -1, -1, -1, -1, -1], // 1
+    [ 0,  1,  1,  1,  1,  1,  1], // 2
+    [ 0,  1,  0,  0,  0,  1,  0], // 3
+    [ 0,  1,  0,  0,  1,  1,  1], // 4
+    [ 0,  1,  0,  5,  1,  1,  0], // 5
+    [ 0,  1,  1,  0,  1,  1,  1], // 6
+];
+
+/// Prohibited directions when unfolding a pentagon.
+///
+/// Indexes by two directions, both relative to the pentagon base cell. The
+/// first is the direction of the origin index and the second is the
+/// direction of the index to unfold. Direction refers to the direction from
+/// base cell to base cell if the indexes are on different base cells, or the
+/// leading digit if within the pentagon base cell.
+///
+/// Any unfolding across more than one icosahedron face is not permitted.
+#[rustfmt::skip]
+const FAILED_DIRECTIONS: [[bool; 7]; 7] = [
+    [false, false, false, false, false, false, false], // 0
+    [false, false, false, false, false, false, false], // 1
+    [false, false, false, false, true,  true,  false], // 2
+    [false, false, false, false, true,  false, true ], // 3
+    [false, false, true,  true,  false, false, false], // 4
+    [false, false, true,  false, false, false, true ], // 5
+    [false, false, false, true,  false, true,  false], // 6
+];
+
+/// Looks up a pentagon rotation count, failing on the deleted k axis entries.
+fn rotations(table: &[[isize; 7]; 7], from: Direction, to: Direction) -> Result<usize> {
+    usize::try_from(table[usize::from(from)][usize::from(to)]).map_err(|_| H3ErrorCode::Failed)
+}
+
+/// Fails with [`H3ErrorCode::Pentagon`] if unfolding a pentagon in these
+/// directions is prohibited.
+fn validate_directions(origin: Direction, index: Direction) -> Result<()> {
+    if FAILED_DIRECTIONS[usize::from(origin)][usize::from(index)] {
+        // we may be unfolding the pentagon incorrectly in this case; return
+        // an error until this is guaranteed to be correct
+        return Err(H3ErrorCode::Pentagon);
+    }
+
+    Ok(())
+}
+
+impl H3Index {
+    /// Produces the local IJ coordinates of `cell`, anchored by `self`.
+    ///
+    /// Fails with [`H3ErrorCode::ResMismatch`] if the resolutions differ,
+    /// [`H3ErrorCode::Failed`] if `cell` is too far from `self` to share a
+    /// local coordinate space, and [`H3ErrorCode::Pentagon`] if pentagon
+    /// distortion prevents unfolding the coordinate space.
+    pub fn cell_to_local_ij(&self, cell: &H3Index) -> Result<CoordIJ> {
+        Ok(CoordIJ::from(&self.cell_to_local_ijk(cell)?))
+    }
+
+    /// Produces the cell at the local IJ coordinates `ij`, anchored by `self`.
+    ///
+    /// This is the inverse of [`H3Index::cell_to_local_ij`]. Fails with
+    /// [`H3ErrorCode::Failed`] if the coordinates are too far from the origin
+    /// and [`H3ErrorCode::Pentagon`] if they fall in a deleted pentagon
+    /// subsequence.
+    pub fn local_ij_to_cell(&self, ij: &CoordIJ) -> Result<H3Index> {
+        self.local_ijk_to_cell(&Coordinate::try_from(ij)?)
+    }
+
+    /// Produces the grid distance between `self` and `cell`, in cells.
+    ///
+    /// Fails with [`H3ErrorCode::ResMismatch`] if the resolutions differ, and
+    /// with [`H3ErrorCode::Failed`] or [`H3ErrorCode::Pentagon`] if the cells
+    /// do not share a local coordinate space; see
+    /// [`H3Index::cell_to_local_ij`].
+    pub fn grid_distance(&self, cell: &H3Index) -> Result<i64> {
+        let origin_ijk = self.cell_to_local_ijk(self)?;
+        let cell_ijk = self.cell_to_local_ijk(cell)?;
+
+        Ok(origin_ijk.distance(&cell_ijk) as i64)
+    }
+
+    /// Returns the number of cells in the line from `self` to `end`, as
+    /// produced by [`H3Index::grid_path_cells`].
+    pub fn grid_path_cells_size(&self, end: &H3Index) -> Result<i64> {
+        Ok(self.grid_distance(end)? + 1)
+    }
+
+    /// Produces the line of cells from `self` to `end`, inclusive.
+    ///
+    /// The path is the minimal contiguous sequence of cells between the two,
+    /// found by linear interpolation in cube coordinates of the local
+    /// coordinate space anchored by `self`. It is not necessarily the
+    /// shortest path in geographic distance, and it fails where
+    /// [`H3Index::grid_distance`] fails.
+    pub fn grid_path_cells(&self, end: &H3Index) -> Result<Vec<H3Index>> {
+        let distance = self.grid_distance(end)?;
+
+        // get ijk coords for the start and end, converted to cube coordinates
+        // suitable for linear interpolation
+        let start_ijk = self.cell_to_local_ijk(self)?.to_cube();
+        let end_ijk = self.cell_to_local_ijk(end)?.to_cube();
+
+        let step = |start: isize, end: isize| {
+            if distance == 0 {
+                0.
+            } else {
+                (end - start) as f64 / distance as f64
+            }
+        };
+        let i_step = step(start_ijk.i, end_ijk.i);
+        let j_step = step(start_ijk.j, end_ijk.j);
+        let k_step = step(start_ijk.k, end_ijk.k);
+
+        (0..=distance)
+            .map(|n| {
+                let n = n as f64;
+                let current = Coordinate::cube_round(
+                    start_ijk.i as f64 + i_step * n,
+                    start_ijk.j as f64 + j_step * n,
+                    start_ijk.k as f64 + k_step * n,
+                );
+
+                self.local_ijk_to_cell(&Coordinate::from_cube(current))
+            })
+            .collect()
+    }
+
+    /// Produces the ijk+ coordinates of `cell` in the local coordinate space
+    /// anchored by `self`. See [`H3Index::cell_to_local_ij`].
+    pub(crate) fn cell_to_local_ijk(&self, cell: &H3Index) -> Result<Coordinate> {
+        let res = self.resolution();
+        if res != cell.resolution() {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        if !self.valid_cell() || !cell.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        let origin_base_cell = BaseCell::try_from(self.base_cell())?;
+        let base_cell = BaseCell::try_from(cell.base_cell())?;
+
+        // direction from origin base cell to index base cell
+        let (dir, mut rev_dir) = if origin_base_cell == base_cell {
+            (Direction::Center, Direction::Center)
+        } else {
+            // base cells that are not neighbors can't be unfolded
+            let dir = origin_base_cell
+                .direction(base_cell)
+                .ok_or(H3ErrorCode::Failed)?;
+            let rev_dir = base_cell
+                .direction(origin_base_cell)
+                .ok_or(H3ErrorCode::Failed)?;
+            (dir, rev_dir)
+        };
+
+        let origin_on_pent = origin_base_cell.is_pentagon();
+        let index_on_pent = base_cell.is_pentagon();
+
+        let mut h3 = cell.clone();
+        if dir != Direction::Center {
+            // rotate index into the orientation of the origin base cell; cw
+            // because we are undoing the rotation into that base cell
+            let base_cell_rotations = origin_base_cell
+                .neighbor_rotations(dir)
+                .ok_or(H3ErrorCode::Failed)?;
+
+            for _ in 0..base_cell_rotations {
+                if index_on_pent {
+                    h3 = h3.rotate_pent_60_cw();
+                    rev_dir = rev_dir.rotate_60_cw();
+                    if rev_dir == Direction::KAxes {
+                        rev_dir = rev_dir.rotate_60_cw();
+                    }
+                } else {
+                    h3 = h3.rotate_60_cw();
+                    rev_dir = rev_dir.rotate_60_cw();
+                }
+            }
+        }
+
+        // face is unused; this produces coordinates in base cell coordinate
+        // space
+        let mut index_fijk = Face::default();
+        h3.to_face_ijk_with_initialized_fijk(&mut index_fijk);
+        let mut ijk = index_fijk.coord;
+
+        if dir != Direction::Center {
+            debug_assert!(!(origin_on_pent && index_on_pent));
+
+            let (pentagon_rotations, direction_rotations) = if origin_on_pent {
+                let origin_leading_digit = self.leading_non_zero_digit();
+                validate_directions(origin_leading_digit, dir)?;
+
+                let rotations = rotations(&PENTAGON_ROTATIONS, origin_leading_digit, dir)?;
+                (rotations, rotations)
+            } else if index_on_pent {
+                let index_leading_digit = h3.leading_non_zero_digit();
+                validate_directions(index_leading_digit, rev_dir)?;
+
+                (
+                    rotations(&PENTAGON_ROTATIONS, rev_dir, index_leading_digit)?,
+                    0,
+                )
+            } else {
+                (0, 0)
+            };
+
+            ijk = (0..pentagon_rotations).fold(ijk, |ijk, _| ijk.rotate_60_cw());
+
+            // scale offset based on resolution
+            let mut offset = Coordinate::default().neighbor(dir);
+            for r in (1..=res).rev() {
+                offset = if is_resolution_class_iii(r) {
+                    offset.down_ap7()
+                } else {
+                    offset.down_ap7r()
+                };
+            }
+
+            offset = (0..direction_rotations).fold(offset, |ijk, _| ijk.rotate_60_cw());
+
+            // perform necessary translation
+            ijk = (ijk + offset).normalize();
+        } else if origin_on_pent && index_on_pent {
+            // if the origin and index are on pentagon, and we checked that
+            // the base cells are the same or neighboring, then they must be
+            // the same base cell
+            let origin_leading_digit = self.leading_non_zero_digit();
+            let index_leading_digit = h3.leading_non_zero_digit();
+            validate_directions(origin_leading_digit, index_leading_digit)?;
+
+            let within_pentagon_rotations = rotations(
+                &PENTAGON_ROTATIONS,
+                origin_leading_digit,
+                index_leading_digit,
+            )?;
+            ijk = (0..within_pentagon_rotations).fold(ijk, |ijk, _| ijk.rotate_60_cw());
+        }
+
+        Ok(ijk)
+    }
+
+    /// Produces the cell at the ijk+ coordinates `ijk` in the local
+    /// coordinate space anchored by `self`. See [`H3Index::local_ij_to_cell`].
+    pub(crate) fn local_ijk_to_cell(&self, ijk: &Coordinate) -> Result<H3Index> {
+        if !self.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        let res = self.resolution();
+        let origin_base_cell = BaseCell::try_from(self.base_cell())?;
+        let origin_on_pent = origin_base_cell.is_pentagon();
+
+        // this logic is very similar to `H3Index::from_face_ijk`
+        let mut out = Self(H3_INIT).set_mode(H3_CELL_MODE).set_resolution(res);
+
+        // check for res 0/base cell
+        if res == 0 {
+            // bail out if we're moving in an invalid direction off a pentagon
+            let base_cell = origin_base_cell
+                .neighbor(Direction::from(ijk))
+                .ok_or(H3ErrorCode::Failed)?;
+            return Ok(out.set_base_cell(base_cell.into()));
+        }
+
+        // local coordinates of any cell fit in 32 bits, as in the reference
+        // implementation; larger ones could overflow when re-expressed in the
+        // aperture 7 grids below
+        if [ijk.i, ijk.j, ijk.k]
+            .iter()
+            .any(|c| i32::try_from(*c).is_err())
+        {
+            return Err(H3ErrorCode::Failed);
+        }
+
+        // we need to find the correct base cell offset (if any) for this H3
+        // index; start with the passed in base cell and resolution res ijk
+        // coordinates in that base cell's coordinate system
+        let mut ijk = *ijk;
+
+        // build the H3Index from finest res up
+        for r in (1..=res).rev() {
+            let last_ijk = ijk;
+            let last_center = if is_resolution_class_iii(r) {
+                // rotate ccw
+                ijk = ijk.up_ap7();
+                ijk.down_ap7()
+            } else {
+                // rotate cw
+                ijk = ijk.up_ap7r();
+                ijk.down_ap7r()
+            };
+
+            let diff = (last_ijk - last_center).normalize();
+            out = out.set_index_digit(r, Direction::from(&diff));
+        }
+
+        // ijk should now hold the IJK of the base cell in the coordinate
+        // system of the current base cell
+        if ijk.i > 1 || ijk.j > 1 || ijk.k > 1 {
+            // out of range input
+            return Err(H3ErrorCode::Failed);
+        }
+
+        // lookup the correct base cell
+        let mut dir = Direction::from(&ijk);
+        let mut base_cell = origin_base_cell.neighbor(dir);
+        // if the base cell is invalid, it must be because the origin base
+        // cell is a pentagon, and because pentagon base cells do not border
+        // each other, the base cell must not be a pentagon
+        let index_on_pent = base_cell.is_some_and(BaseCell::is_pentagon);
+
+        if dir != Direction::Center {
+            // if the index is in a warped direction, we need to unwarp the
+            // base cell direction; there may be further need to rotate the
+            // index digits
+            let mut pentagon_rotations = 0;
+            if origin_on_pent {
+                let origin_leading_digit = self.leading_non_zero_digit();
+                pentagon_rotations =
+                    rotations(&PENTAGON_ROTATIONS_REVERSE, origin_leading_digit, dir)?;
+                dir = (0..pentagon_rotations).fold(dir, |dir, _| dir.rotate_60_ccw());
+
+                // the pentagon rotations are being chosen so that dir is not
+                // the deleted direction; if it still happens, it means we're
+                // moving into a deleted subsequence, so there is no index here
+                if dir == Direction::KAxes {
+                    return Err(H3ErrorCode::Pentagon);
+                }
+
+                base_cell = origin_base_cell.neighbor(dir);
+            }
+
+            let base_cell = base_cell.ok_or(H3ErrorCode::Failed)?;
+
+            // now we can determine the relation between the origin and target
+            // base cell
+            let base_cell_rotations = origin_base_cell
+                .neighbor_rotations(dir)
+                .ok_or(H3ErrorCode::Failed)?;
+
+            // adjust for pentagon warping within the base cell; the base cell
+            // should be in the right location, so now we need to rotate the
+            // index back
+            if index_on_pent {
+                let rev_dir = base_cell
+                    .direction(origin_base_cell)
+                    .ok_or(H3ErrorCode::Failed)?;
+
+                // adjust for the different coordinate space in the two base
+                // cells; this is done first because we need to do the
+                // pentagon rotations based on the leading digit in the
+                // pentagon's coordinate system
+                for _ in 0..base_cell_rotations {
+                    out = out.rotate_60_ccw();
+                }
+
+                let index_leading_digit = out.leading_non_zero_digit();
+                let table = if base_cell.is_polar_pentagon() {
+                    &PENTAGON_ROTATIONS_REVERSE_POLAR
+                } else {
+                    &PENTAGON_ROTATIONS_REVERSE_NONPOLAR
+                };
+
+                for _ in 0..rotations(table, rev_dir, index_leading_digit)? {
+                    out = out.rotate_pent_60_ccw();
+                }
+            } else {
+                for _ in 0..pentagon_rotations + base_cell_rotations as usize {
+                    out = out.rotate_60_ccw();
+                }
+            }
+        } else if origin_on_pent && index_on_pent {
+            let origin_leading_digit = self.leading_non_zero_digit();
+            let index_leading_digit = out.leading_non_zero_digit();
+
+            let within_pentagon_rotations = rotations(
+                &PENTAGON_ROTATIONS_REVERSE,
+                origin_leading_digit,
+                index_leading_digit,
+            )?;
+            for _ in 0..within_pentagon_rotations {
+                out = out.rotate_60_ccw();
+            }
+        }
+
+        // there are cases in `cell_to_local_ijk` which are failed but not
+        // accounted for here; instead just fail if the recovered index is
+        // invalid
+        if index_on_pent && out.leading_non_zero_digit() == Direction::KAxes {
+            return Err(H3ErrorCode::Pentagon);
+        }
+
+        let base_cell = base_cell.ok_or(H3ErrorCode::Failed)?;
+        Ok(out.set_base_cell(base_cell.into()))
+    }
+}
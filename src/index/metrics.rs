@@ -0,0 +1,55 @@
+//! Exact areas of cells and lengths of edges, measured on their boundaries.
+use super::H3Index;
+use crate::{consts::EARTH_RADIUS_KM, latlng::triangle_area_rads2, result::Result};
+
+impl H3Index {
+    /// Computes the exact area of the cell in radians^2, as the sum of the
+    /// spherical triangles between the cell's center and each edge of its
+    /// boundary.
+    pub fn cell_area_rads2(&self) -> Result<f64> {
+        let center = self.to_latlng()?;
+        let boundary = self.boundary()?;
+        let verts = boundary.verts();
+
+        Ok(verts
+            .iter()
+            .zip(verts.iter().cycle().skip(1))
+            .map(|(a, b)| triangle_area_rads2(a, b, &center))
+            .sum())
+    }
+
+    /// Computes the exact area of the cell in square kilometers.
+    pub fn cell_area_km2(&self) -> Result<f64> {
+        Ok(self.cell_area_rads2()? * EARTH_RADIUS_KM * EARTH_RADIUS_KM)
+    }
+
+    /// Computes the exact area of the cell in square meters.
+    pub fn cell_area_m2(&self) -> Result<f64> {
+        Ok(self.cell_area_km2()? * 1000. * 1000.)
+    }
+
+    /// Computes the exact length of the directed edge in radians, along its
+    /// boundary.
+    ///
+    /// Fails with [`H3ErrorCode::DirEdgeInvalid`](crate::result::H3ErrorCode::DirEdgeInvalid)
+    /// if the index is not a directed edge.
+    pub fn edge_length_rads(&self) -> Result<f64> {
+        let boundary = self.directed_edge_to_boundary()?;
+
+        Ok(boundary
+            .verts()
+            .windows(2)
+            .map(|pair| pair[0].great_circle_distance_rads(&pair[1]))
+            .sum())
+    }
+
+    /// Computes the exact length of the directed edge in kilometers.
+    pub fn edge_length_km(&self) -> Result<f64> {
+        Ok(self.edge_length_rads()? * EARTH_RADIUS_KM)
+    }
+
+    /// Computes the exact length of the directed edge in meters.
+    pub fn edge_length_m(&self) -> Result<f64> {
+        Ok(self.edge_length_km()? * 1000.)
+    }
+}
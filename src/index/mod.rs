@@ -1,19 +1,36 @@
 #![allow(dead_code)]
 pub(crate) mod consts;
+mod directed_edge;
+mod hierarchy;
+mod local_ij;
+mod metrics;
+mod traversal;
+mod undirected_edge;
+mod vertex;
 
 use core::ops::{BitAnd, Shl, Shr};
 
 use derive_new::new;
 
 use crate::{
-    base_cell::is_base_cell_pentagon, consts::H3_CELL_MODE, direction::Direction,
-    result::H3ErrorCode, MAX_H3_RES, NUM_BASE_CELLS,
+    base_cell::{face_ijk_to_base_cell_rotation, is_base_cell_pentagon, BaseCell},
+    cell_boundary::CellBoundary,
+    consts::H3_CELL_MODE,
+    coordinate::Coordinate,
+    direction::Direction,
+    face::{Face, FaceIJK, Hexagon, Overage, Pentagon, Shape},
+    is_resolution_class_iii,
+    latlng::LatLng,
+    result::{H3ErrorCode, Result},
+    MAX_H3_RES, NUM_BASE_CELLS,
 };
 pub use consts::*;
+pub use hierarchy::{compact_cells, uncompact_cells, uncompact_cells_size, Children};
+pub use traversal::max_grid_disk_size;
 
 /// Identifier for an object (cell, edge, etc) in the H3System.
 /// The H3Index fits within a 64-bit unsigned integer.
-#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, new)]
+#[derive(Clone, Debug, PartialEq, PartialOrd, Eq, Ord, Hash, new)]
 pub struct H3Index(u64);
 
 impl H3Index {
@@ -25,12 +42,190 @@ impl H3Index {
             .set_base_cell(base_cell);
 
         for r in 1..=resolution {
-            h3 = h3.set_index_digit(r, direction.clone());
+            h3 = h3.set_index_digit(r, direction);
         }
 
         h3
     }
 
+    /// Finds the cell containing `g` at resolution `res`.
+    pub fn from_latlng(g: &LatLng, res: usize) -> Result<Self> {
+        if res > MAX_H3_RES as usize {
+            return Err(H3ErrorCode::ResDomain);
+        }
+
+        if !g.is_finite() {
+            return Err(H3ErrorCode::LatLngDomain);
+        }
+
+        Self::from_face_ijk(&Face::from_geo(g, res), res)
+    }
+
+    /// Converts a [`Face`] address to the corresponding cell at resolution `res`.
+    pub(crate) fn from_face_ijk(fijk: &Face, res: usize) -> Result<Self> {
+        // initialize the index
+        let mut h3 = Self(H3_INIT).set_mode(H3_CELL_MODE).set_resolution(res);
+
+        // check for res 0/base cell
+        if res == 0 {
+            let rotation = face_ijk_to_base_cell_rotation(fijk).ok_or(H3ErrorCode::Failed)?;
+            return Ok(h3.set_base_cell(rotation.base_cell.into()));
+        }
+
+        // we need to find the correct base cell FaceIJK for this H3 index;
+        // start with the passed in face and resolution res ijk coordinates
+        // in that face's coordinate system
+        let mut fijk_bc = fijk.clone();
+
+        // build the H3Index from finest res up
+        // adjust r for the fact that the res 0 base cell offsets the indexing
+        // digits
+        for r in (0..res).rev() {
+            let last_ijk = fijk_bc.coord;
+            let last_center = if is_resolution_class_iii(r + 1) {
+                // rotate ccw
+                fijk_bc.coord = fijk_bc.coord.up_ap7();
+                fijk_bc.coord.down_ap7()
+            } else {
+                // rotate cw
+                fijk_bc.coord = fijk_bc.coord.up_ap7r();
+                fijk_bc.coord.down_ap7r()
+            };
+
+            let diff = (last_ijk - last_center).normalize();
+            h3 = h3.set_index_digit(r + 1, Direction::from(&diff));
+        }
+
+        // fijk_bc should now hold the IJK of the base cell in the
+        // coordinate system of the current face
+        let rotation = face_ijk_to_base_cell_rotation(&fijk_bc).ok_or(H3ErrorCode::Failed)?;
+        let base_cell = rotation.base_cell;
+        h3 = h3.set_base_cell(base_cell.into());
+
+        // rotate if necessary to get canonical base cell orientation
+        // for this base cell
+        if base_cell.is_pentagon() {
+            // force rotation out of missing k-axes sub-sequence
+            if h3.leading_non_zero_digit() == Direction::KAxes {
+                // check for a cw/ccw offset face; default is ccw
+                h3 = if base_cell.is_cw_offset(fijk_bc.face) {
+                    h3.rotate_60_cw()
+                } else {
+                    h3.rotate_60_ccw()
+                };
+            }
+
+            for _ in 0..rotation.ccw_rotation_60 {
+                h3 = h3.rotate_pent_60_ccw();
+            }
+        } else {
+            for _ in 0..rotation.ccw_rotation_60 {
+                h3 = h3.rotate_60_ccw();
+            }
+        }
+
+        Ok(h3)
+    }
+
+    /// Determines the spherical coordinates of the center point of the cell.
+    pub fn to_latlng(&self) -> Result<LatLng> {
+        self.to_face_ijk()?.to_geo(self.resolution())
+    }
+
+    /// Finds the boundary of the cell.
+    pub fn boundary(&self) -> Result<CellBoundary> {
+        let res = self.resolution();
+
+        if self.is_pentagon() {
+            FaceIJK::<Pentagon>::try_from(self)?.to_cell_boundary(res, 0, Pentagon::NUM_VERTS)
+        } else {
+            FaceIJK::<Hexagon>::try_from(self)?.to_cell_boundary(res, 0, Hexagon::NUM_VERTS)
+        }
+    }
+
+    /// Returns whether the cell is a pentagon.
+    pub fn is_pentagon(&self) -> bool {
+        is_base_cell_pentagon(self.base_cell())
+            && self.leading_non_zero_digit() == Direction::Center
+    }
+
+    /// Converts the cell to its [`Face`] address on the icosahedral face
+    /// containing the cell's center.
+    pub(crate) fn to_face_ijk(&self) -> Result<Face> {
+        let base_cell = BaseCell::try_from(self.base_cell())?;
+
+        // start with the "home" face and ijk+ coordinates for the base cell
+        let mut fijk = base_cell.to_face_ijk();
+
+        // adjust for the pentagonal missing sequence; all of sub-sequence 5
+        // needs to be adjusted (and not just sub-sequence 5 of the base cell)
+        let h3 = if base_cell.is_pentagon() && self.leading_non_zero_digit() == Direction::IKAxes {
+            self.clone().rotate_60_cw()
+        } else {
+            self.clone()
+        };
+
+        if !h3.to_face_ijk_with_initialized_fijk(&mut fijk) {
+            // no overage is possible; h3 lies on this face
+            return Ok(fijk);
+        }
+
+        // if we're here we have the potential for an "overage"; i.e., it is
+        // possible that the cell lies on an adjacent face
+        let orig_ijk = fijk.coord;
+
+        // if we're in Class III, drop into the next finer Class II grid
+        let mut res = h3.resolution();
+        if is_resolution_class_iii(res) {
+            fijk.coord = fijk.coord.down_ap7r();
+            res += 1;
+        }
+
+        // adjust for overage if needed; a pentagon base cell with a leading 4
+        // digit requires special handling
+        let pent_leading_4 =
+            base_cell.is_pentagon() && h3.leading_non_zero_digit() == Direction::IAxes;
+        if fijk.adjust_overage_class_ii(res, pent_leading_4, false) != Overage::NoOverage {
+            // if the base cell is a pentagon we have the potential for
+            // secondary overages
+            if base_cell.is_pentagon() {
+                while fijk.adjust_overage_class_ii(res, false, false) != Overage::NoOverage {}
+            }
+
+            if res != h3.resolution() {
+                fijk.coord = fijk.coord.up_ap7r();
+            }
+        } else if res != h3.resolution() {
+            fijk.coord = orig_ijk;
+        }
+
+        Ok(fijk)
+    }
+
+    /// Converts the cell to a [`Face`] address in the coordinate system of
+    /// `fijk`, which must be initialized to the home face of the cell's base
+    /// cell. Returns whether an overage onto another face is possible.
+    fn to_face_ijk_with_initialized_fijk(&self, fijk: &mut Face) -> bool {
+        let res = self.resolution();
+
+        // center base cell hierarchy is entirely on this face
+        let possible_overage = is_base_cell_pentagon(self.base_cell())
+            || (res != 0 && fijk.coord != Coordinate::new(0, 0, 0));
+
+        for r in 1..=res {
+            fijk.coord = if is_resolution_class_iii(r) {
+                // Class III == rotate ccw
+                fijk.coord.down_ap7()
+            } else {
+                // Class II == rotate cw
+                fijk.coord.down_ap7r()
+            }
+            .neighbor(self.index_digit(r));
+        }
+
+        possible_overage
+    }
+
     pub fn high_bit(&self) -> usize {
         ((self & H3_HIGH_BIT_MASK) >> H3_MAX_OFFSET) as usize
     }
@@ -72,10 +267,91 @@ impl H3Index {
 
     /// Gets the resolution digit (0-7)
     pub fn index_digit(&self, resolution: usize) -> Direction {
-        (((self >> (MAX_H3_RES - (resolution as u64))) * H3_PER_DIGIT_OFFSET) & H3_DIGIT_MASK)
+        ((self >> ((MAX_H3_RES - (resolution as u64)) * H3_PER_DIGIT_OFFSET)) & H3_DIGIT_MASK)
             .into()
     }
 
+    /// Returns the highest resolution non-zero digit in the index, or
+    /// [`Direction::Center`] if all digits are zero.
+    pub fn leading_non_zero_digit(&self) -> Direction {
+        (1..=self.resolution())
+            .map(|r| self.index_digit(r))
+            .find(|digit| *digit != Direction::Center)
+            .unwrap_or(Direction::Center)
+    }
+
+    /// Rotates the index digits 60 degrees counter-clockwise. Consumes `self`.
+    pub(crate) fn rotate_60_ccw(self) -> Self {
+        (1..=self.resolution()).fold(self, |h3, r| {
+            let digit = h3.index_digit(r).rotate_60_ccw();
+            h3.set_index_digit(r, digit)
+        })
+    }
+
+    /// Rotates the index digits 60 degrees clockwise. Consumes `self`.
+    pub(crate) fn rotate_60_cw(self) -> Self {
+        (1..=self.resolution()).fold(self, |h3, r| {
+            let digit = h3.index_digit(r).rotate_60_cw();
+            h3.set_index_digit(r, digit)
+        })
+    }
+
+    /// Rotates the index digits of a pentagon 60 degrees counter-clockwise,
+    /// rotating again whenever the result would enter the deleted k-axes
+    /// sub-sequence. Consumes `self`.
+    pub(crate) fn rotate_pent_60_ccw(self) -> Self {
+        let mut h3 = self;
+        let mut found_first_non_zero_digit = false;
+
+        for r in 1..=h3.resolution() {
+            // rotate this digit
+            let digit = h3.index_digit(r).rotate_60_ccw();
+            h3 = h3.set_index_digit(r, digit);
+
+            // look for the first non-zero digit so we
+            // can adjust for deleted k-axes sequence
+            // if necessary
+            if !found_first_non_zero_digit && digit != Direction::Center {
+                found_first_non_zero_digit = true;
+
+                // adjust for deleted k-axes sequence
+                if h3.leading_non_zero_digit() == Direction::KAxes {
+                    h3 = h3.rotate_60_ccw();
+                }
+            }
+        }
+
+        h3
+    }
+
+    /// Rotates the index digits of a pentagon 60 degrees clockwise,
+    /// rotating again whenever the result would enter the deleted k-axes
+    /// sub-sequence. Consumes `self`.
+    pub(crate) fn rotate_pent_60_cw(self) -> Self {
+        let mut h3 = self;
+        let mut found_first_non_zero_digit = false;
+
+        for r in 1..=h3.resolution() {
+            // rotate this digit
+            let digit = h3.index_digit(r).rotate_60_cw();
+            h3 = h3.set_index_digit(r, digit);
+
+            // look for the first non-zero digit so we
+            // can adjust for deleted k-axes sequence
+            // if necessary
+            if !found_first_non_zero_digit && digit != Direction::Center {
+                found_first_non_zero_digit = true;
+
+                // adjust for deleted k-axes sequence
+                if h3.leading_non_zero_digit() == Direction::KAxes {
+                    h3 = h3.rotate_60_cw();
+                }
+            }
+        }
+
+        h3
+    }
+
     /// Sets the resolution of the index.
     pub(crate) fn set_index_digit<D>(self, resolution: usize, direction: D) -> Self
     where
@@ -142,16 +418,18 @@ impl H3Index {
     }
 }
 
+/// Formats the index as lowercase hexadecimal without a leading `0x`, the
+/// canonical string representation of an H3 index.
 impl core::fmt::Display for H3Index {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:#x}", self.0)
+        write!(f, "{:x}", self.0)
     }
 }
 
 impl TryFrom<String> for H3Index {
     type Error = H3ErrorCode;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+    fn try_from(value: String) -> core::result::Result<Self, Self::Error> {
         value.as_str().try_into()
     }
 }
@@ -159,9 +437,12 @@ impl TryFrom<String> for H3Index {
 impl TryFrom<&str> for H3Index {
     type Error = H3ErrorCode;
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        value
-            .parse::<u64>()
+    /// Parses the hexadecimal string representation of an index, with or
+    /// without a leading `0x`.
+    fn try_from(value: &str) -> core::result::Result<Self, Self::Error> {
+        let digits = value.strip_prefix("0x").unwrap_or(value);
+
+        u64::from_str_radix(digits, 16)
             .map(H3Index)
             .map_err(|_| H3ErrorCode::Failed)
     }
@@ -169,7 +450,7 @@ impl TryFrom<&str> for H3Index {
 
 impl From<H3Index> for String {
     fn from(h3: H3Index) -> Self {
-        format!("{}", h3.0)
+        h3.to_string()
     }
 }
 
@@ -243,11 +524,17 @@ mod tests {
         let h3 = debug_index(H3Index::init(3, 4, Direction::Center));
         assert_eq!(3, h3.resolution());
         assert_eq!(4, h3.base_cell());
-        // TODO: figure this out
-        // assert_eq!(
-        //     Into::<usize>::into(Direction::Center),
-        //     h3.index_digit(3).into()
-        // );
+        assert_eq!(Direction::Center, h3.index_digit(3));
+    }
+
+    #[test]
+    fn h3_to_latlng() {
+        let center = H3Index::try_from("85283473fffffff")
+            .unwrap()
+            .to_latlng()
+            .unwrap();
+        assert!((center.lat_degrees() - 37.345793375368).abs() < 1e-9);
+        assert!((center.lng_degrees() - -121.976375972551).abs() < 1e-9);
     }
 
     #[test]
@@ -282,9 +569,39 @@ mod tests {
     fn h3_index_digit() {
         let h3 = debug_index(H3Index::init(12, 4, Direction::IJAxes));
         for r in 1..=12 {
-            println!("Digit at res {}: {:?}", r, h3.index_digit(r))
+            assert_eq!(Direction::IJAxes, h3.index_digit(r));
         }
-        // TODO: figure this out
+
+        for r in 13..=15 {
+            assert_eq!(Direction::Invalid, h3.index_digit(r));
+        }
+    }
+
+    #[test]
+    fn h3_string_round_trip() {
+        let h3 = H3Index::try_from("85283473fffffff").unwrap();
+        assert_eq!(0x85283473fffffff, h3.0);
+        assert_eq!(h3, H3Index::try_from("0x85283473fffffff").unwrap());
+        assert_eq!("85283473fffffff", h3.to_string());
+        assert_eq!("85283473fffffff", String::from(h3));
+        assert_eq!(
+            Err(H3ErrorCode::Failed),
+            H3Index::try_from("599686042433355775")
+        );
+    }
+
+    #[test]
+    fn h3_from_latlng() {
+        let g = LatLng::from_degrees(37.3615593, -122.0553238);
+        assert_eq!(
+            H3Index::try_from("85283473fffffff").unwrap(),
+            H3Index::from_latlng(&g, 5).unwrap()
+        );
+        assert_eq!(Err(H3ErrorCode::ResDomain), H3Index::from_latlng(&g, 16));
+        assert_eq!(
+            Err(H3ErrorCode::LatLngDomain),
+            H3Index::from_latlng(&LatLng::new(f64::NAN, 0.), 5)
+        );
     }
 
     #[test]
@@ -0,0 +1,509 @@
+use std::collections::HashMap;
+
+use super::H3Index;
+use crate::{
+    base_cell::BaseCell,
+    direction::Direction,
+    is_resolution_class_iii,
+    result::{H3ErrorCode, Result},
+    MAX_H3_RES,
+};
+
+use Direction::{
+    Center as CENTER, IAxes as I, IJAxes as IJ, IKAxes as IK, JAxes as J, JKAxes as JK, KAxes as K,
+};
+
+/// Directions used for traversing a hexagonal ring counterclockwise around
+/// {1, 0, 0}.
+///
+/// ```text
+///      _
+///    _/ \_
+///   / \5/ \
+///   \0/ \4/
+///   / \_/ \
+///   \1/ \3/
+///     \2/
+/// ```
+pub(crate) const DIRECTIONS: [Direction; 6] = [J, JK, K, IK, I, IJ];
+
+/// Direction used for traversing to the next outward hexagonal ring.
+pub(crate) const NEXT_RING_DIRECTION: Direction = I;
+
+/// New digit when traversing along class II grids.
+///
+/// Current digit -> direction -> new digit.
+const NEW_DIGIT_II: [[Direction; 7]; 7] = [
+    [CENTER, K, J, JK, I, IK, IJ],
+    [K, I, JK, IJ, IK, J, CENTER],
+    [J, JK, K, I, IJ, CENTER, IK],
+    [JK, IJ, I, IK, CENTER, K, J],
+    [I, IK, IJ, CENTER, J, JK, K],
+    [IK, J, CENTER, K, JK, IJ, I],
+    [IJ, CENTER, IK, J, K, I, JK],
+];
+
+/// New traversal direction when traversing along class II grids.
+///
+/// Current digit -> direction -> new ap7 move (at coarser level).
+const NEW_ADJUSTMENT_II: [[Direction; 7]; 7] = [
+    [CENTER, CENTER, CENTER, CENTER, CENTER, CENTER, CENTER],
+    [CENTER, K, CENTER, K, CENTER, IK, CENTER],
+    [CENTER, CENTER, J, JK, CENTER, CENTER, J],
+    [CENTER, K, JK, JK, CENTER, CENTER, CENTER],
+    [CENTER, CENTER, CENTER, CENTER, I, I, IJ],
+    [CENTER, IK, CENTER, CENTER, I, IK, CENTER],
+    [CENTER, CENTER, J, CENTER, IJ, CENTER, IJ],
+];
+
+/// New digit when traversing along class III grids.
+///
+/// Current digit -> direction -> new digit.
+const NEW_DIGIT_III: [[Direction; 7]; 7] = [
+    [CENTER, K, J, JK, I, IK, IJ],
+    [K, J, JK, I, IK, IJ, CENTER],
+    [J, JK, I, IK, IJ, CENTER, K],
+    [JK, I, IK, IJ, CENTER, K, J],
+    [I, IK, IJ, CENTER, K, J, JK],
+    [IK, IJ, CENTER, K, J, JK, I],
+    [IJ, CENTER, K, J, JK, I, IK],
+];
+
+/// New traversal direction when traversing along class III grids.
+///
+/// Current digit -> direction -> new ap7 move (at coarser level).
+const NEW_ADJUSTMENT_III: [[Direction; 7]; 7] = [
+    [CENTER, CENTER, CENTER, CENTER, CENTER, CENTER, CENTER],
+    [CENTER, K, CENTER, JK, CENTER, K, CENTER],
+    [CENTER, CENTER, J, J, CENTER, CENTER, IJ],
+    [CENTER, JK, J, JK, CENTER, CENTER, CENTER],
+    [CENTER, CENTER, CENTER, CENTER, I, IK, I],
+    [CENTER, K, CENTER, CENTER, IK, IK, CENTER],
+    [CENTER, CENTER, IJ, CENTER, I, CENTER, IJ],
+];
+
+/// Smallest `k` for which a grid disk covers every cell at the finest
+/// resolution.
+const K_ALL_CELLS_AT_MAX_RES: usize = 13_780_510;
+
+/// Maximum number of cells that result from the grid disk algorithm with the
+/// given `k`. Disks that would cover the whole globe are clamped to the number
+/// of cells at the finest resolution.
+pub fn max_grid_disk_size(k: usize) -> usize {
+    if k >= K_ALL_CELLS_AT_MAX_RES {
+        return num_cells(MAX_H3_RES as usize);
+    }
+
+    3 * k * (k + 1) + 1
+}
+
+/// Number of cells at resolution `res`: 2 pentagons and 120 hexagons at res
+/// 0, with each hexagon having 7 children and each pentagon 6.
+fn num_cells(res: usize) -> usize {
+    2 + 120 * 7usize.pow(res as u32)
+}
+
+/// Creates an empty vector with room for `capacity` elements, bounded by the
+/// number of cells at resolution `res`.
+///
+/// Fails with [`H3ErrorCode::MemoryAlloc`] if the memory cannot be allocated.
+fn with_cell_capacity<T>(capacity: usize, res: usize) -> Result<Vec<T>> {
+    let mut out = Vec::new();
+    out.try_reserve(capacity.min(num_cells(res)))
+        .map_err(|_| H3ErrorCode::MemoryAlloc)?;
+
+    Ok(out)
+}
+
+impl H3Index {
+    /// Returns the cell neighboring `self` in direction `dir`, along with the
+    /// number of 60 degree counter-clockwise rotations to apply to directions
+    /// relative to `self` to make them relative to the neighbor. The rotation
+    /// count is non-zero when crossing into a base cell with a different
+    /// orientation.
+    ///
+    /// [`Direction::Center`] returns `self`. Fails with
+    /// [`H3ErrorCode::Pentagon`] when moving in the deleted k direction
+    /// ([`Direction::PentagonSkippedDigit`]) from the center of a pentagon.
+    pub fn neighbor(&self, dir: Direction) -> Result<(Self, usize)> {
+        self.neighbor_rotations(dir, 0)
+    }
+
+    /// Returns the cell neighboring `self` in direction `dir`, after rotating
+    /// `dir` counter-clockwise `rotations` times, along with the updated
+    /// number of rotations; the rotation count changes when crossing into a
+    /// base cell with a different orientation.
+    ///
+    /// Fails with [`H3ErrorCode::Pentagon`] when moving into the deleted k
+    /// subsequence from the center of a pentagon.
+    pub(crate) fn neighbor_rotations(
+        &self,
+        dir: Direction,
+        rotations: usize,
+    ) -> Result<(Self, usize)> {
+        // the skipped digit is an alias of the k axes
+        let dir = match dir {
+            Direction::PentagonSkippedDigit => K,
+            dir if (CENTER..=IJ).contains(&dir) => dir,
+            _ => return Err(H3ErrorCode::Failed),
+        };
+
+        let mut rotations = rotations % 6;
+        let mut dir = (0..rotations).fold(dir, |dir, _| dir.rotate_60_ccw());
+
+        let old_base_cell = BaseCell::try_from(self.base_cell())?;
+        let old_home_face = old_base_cell.to_face_ijk();
+        let old_leading_digit = self.leading_non_zero_digit();
+
+        let mut current = self.clone();
+        let mut new_rotations = 0;
+
+        // adjust the indexing digits and, if needed, the base cell
+        let mut r = current.resolution();
+        loop {
+            if r == 0 {
+                match old_base_cell.neighbor(dir) {
+                    Some(base_cell) => {
+                        current = current.set_base_cell(base_cell.into());
+                        new_rotations = old_base_cell
+                            .neighbor_rotations(dir)
+                            .ok_or(H3ErrorCode::Failed)?;
+                    }
+                    None => {
+                        // adjust for the deleted k vertex at the base cell
+                        // level; this edge actually borders a different
+                        // neighbor
+                        let base_cell = old_base_cell.neighbor(IK).ok_or(H3ErrorCode::Failed)?;
+                        current = current.set_base_cell(base_cell.into());
+                        new_rotations = old_base_cell
+                            .neighbor_rotations(IK)
+                            .ok_or(H3ErrorCode::Failed)?;
+
+                        // perform the adjustment for the k-subsequence we're
+                        // skipping over
+                        current = current.rotate_60_ccw();
+                        rotations += 1;
+                    }
+                }
+
+                break;
+            }
+
+            let old_digit = current.index_digit(r);
+            if old_digit == Direction::Invalid {
+                // only possible on invalid input
+                return Err(H3ErrorCode::CellInvalid);
+            }
+
+            let (from, to) = (usize::from(old_digit), usize::from(dir));
+            let next_dir = if is_resolution_class_iii(r) {
+                current = current.set_index_digit(r, NEW_DIGIT_II[from][to]);
+                NEW_ADJUSTMENT_II[from][to]
+            } else {
+                current = current.set_index_digit(r, NEW_DIGIT_III[from][to]);
+                NEW_ADJUSTMENT_III[from][to]
+            };
+
+            if next_dir == CENTER {
+                // no more adjustment to perform
+                break;
+            }
+
+            dir = next_dir;
+            r -= 1;
+        }
+
+        let new_base_cell = BaseCell::try_from(current.base_cell())?;
+        if new_base_cell.is_pentagon() {
+            let mut already_adjusted_k_subsequence = false;
+
+            // force rotation out of missing k-axes sub-sequence
+            if current.leading_non_zero_digit() == K {
+                if old_base_cell != new_base_cell {
+                    // in this case, we traversed into the deleted k
+                    // subsequence of a pentagon base cell; we need to rotate
+                    // out of that case depending on how we got here, so check
+                    // for a cw/ccw offset face; default is ccw
+                    current = if new_base_cell.is_cw_offset(old_home_face.face) {
+                        current.rotate_60_cw()
+                    } else {
+                        current.rotate_60_ccw()
+                    };
+                    already_adjusted_k_subsequence = true;
+                } else {
+                    // in this case, we traversed into the deleted k
+                    // subsequence from within the same pentagon base cell
+                    match old_leading_digit {
+                        // undefined: the k direction is deleted from here
+                        CENTER => return Err(H3ErrorCode::Pentagon),
+                        JK => {
+                            // rotate out of the deleted k subsequence; we also
+                            // need an additional change to the direction we're
+                            // moving in
+                            current = current.rotate_60_ccw();
+                            rotations += 1;
+                        }
+                        IK => {
+                            // rotate out of the deleted k subsequence; we also
+                            // need an additional change to the direction we're
+                            // moving in
+                            current = current.rotate_60_cw();
+                            rotations += 5;
+                        }
+                        _ => return Err(H3ErrorCode::Failed),
+                    }
+                }
+            }
+
+            for _ in 0..new_rotations {
+                current = current.rotate_pent_60_ccw();
+            }
+
+            // account for differing orientation of the base cells (this edge
+            // might not follow properties of some other edges)
+            if old_base_cell != new_base_cell {
+                if new_base_cell.is_polar_pentagon() {
+                    // 'polar' base cells behave differently because they have
+                    // all i neighbors
+                    if usize::from(old_base_cell) != 118
+                        && usize::from(old_base_cell) != 8
+                        && current.leading_non_zero_digit() != JK
+                    {
+                        rotations += 1;
+                    }
+                } else if current.leading_non_zero_digit() == IK && !already_adjusted_k_subsequence
+                {
+                    // account for distortion introduced to the 5 neighbor by
+                    // the deleted k subsequence
+                    rotations += 1;
+                }
+            }
+        } else {
+            for _ in 0..new_rotations {
+                current = current.rotate_60_ccw();
+            }
+        }
+
+        Ok((current, (rotations + new_rotations as usize) % 6))
+    }
+
+    /// Finds the direction from `self` to the neighboring cell `neighbor`,
+    /// or `None` if the cells are not neighbors.
+    pub(crate) fn direction_for_neighbor(&self, neighbor: &H3Index) -> Option<Direction> {
+        // skip the center, which would be the origin, and the deleted k
+        // direction of pentagons
+        let first = if self.is_pentagon() {
+            Direction::JAxes
+        } else {
+            Direction::KAxes
+        };
+
+        (usize::from(first)..=usize::from(Direction::IJAxes))
+            .map(Direction::from)
+            .find(|&dir| {
+                self.neighbor_rotations(dir, 0)
+                    .is_ok_and(|(h3, _)| &h3 == neighbor)
+            })
+    }
+
+    /// Produces the cells within grid distance `k` of `self`.
+    ///
+    /// k-ring 0 is defined as the origin cell, k-ring 1 is defined as k-ring 0
+    /// and all neighboring cells, and so on.
+    pub fn grid_disk(&self, k: usize) -> Result<Vec<Self>> {
+        Ok(self
+            .grid_disk_distances(k)?
+            .into_iter()
+            .map(|(h3, _)| h3)
+            .collect())
+    }
+
+    /// Produces the cells within grid distance `k` of `self`, paired with
+    /// their distance from `self`.
+    ///
+    /// The fast spiral algorithm of [`H3Index::grid_disk_distances_unsafe`] is
+    /// tried first, falling back to a breadth-first search if pentagonal
+    /// distortion is encountered.
+    pub fn grid_disk_distances(&self, k: usize) -> Result<Vec<(Self, usize)>> {
+        match self.grid_disk_distances_unsafe(k) {
+            Err(H3ErrorCode::Pentagon) => self.grid_disk_distances_safe(k),
+            result => result,
+        }
+    }
+
+    /// Produces the cells within grid distance `k` of `self`, in order of
+    /// increasing distance, using the fast spiral algorithm.
+    ///
+    /// Fails with [`H3ErrorCode::Pentagon`] if a pentagon is encountered, as
+    /// the output would otherwise be incorrect.
+    pub fn grid_disk_unsafe(&self, k: usize) -> Result<Vec<Self>> {
+        Ok(self
+            .grid_disk_distances_unsafe(k)?
+            .into_iter()
+            .map(|(h3, _)| h3)
+            .collect())
+    }
+
+    /// Produces the cells within grid distance `k` of `self`, paired with
+    /// their distance from `self`, in order of increasing distance, using the
+    /// fast spiral algorithm.
+    ///
+    /// Fails with [`H3ErrorCode::Pentagon`] if a pentagon is encountered, as
+    /// the output would otherwise be incorrect.
+    pub fn grid_disk_distances_unsafe(&self, k: usize) -> Result<Vec<(Self, usize)>> {
+        if !self.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        let mut out = with_cell_capacity(max_grid_disk_size(k), self.resolution())?;
+        out.push((self.clone(), 0));
+
+        if self.is_pentagon() {
+            // pentagon was encountered; bail out as user doesn't want this
+            return Err(H3ErrorCode::Pentagon);
+        }
+
+        let mut origin = self.clone();
+        // number of 60 degree ccw rotations to perform on the direction
+        // (based on which faces have been crossed)
+        let mut rotations = 0;
+
+        for ring in 1..=k {
+            // step out to the next ring; not putting in the output set as it
+            // will be done later, at the end of this ring
+            (origin, rotations) = origin.neighbor_rotations(NEXT_RING_DIRECTION, rotations)?;
+            if origin.is_pentagon() {
+                return Err(H3ErrorCode::Pentagon);
+            }
+
+            for direction in DIRECTIONS {
+                for _ in 0..ring {
+                    (origin, rotations) = origin.neighbor_rotations(direction, rotations)?;
+                    out.push((origin.clone(), ring));
+
+                    if origin.is_pentagon() {
+                        return Err(H3ErrorCode::Pentagon);
+                    }
+                }
+            }
+        }
+
+        Ok(out)
+    }
+
+    /// Produces the "hollow" ring of cells at exactly grid distance `k` from
+    /// `self`.
+    ///
+    /// The fast algorithm of [`H3Index::grid_ring_unsafe`] is tried first,
+    /// falling back to filtering [`H3Index::grid_disk_distances`] if
+    /// pentagonal distortion is encountered.
+    pub fn grid_ring(&self, k: usize) -> Result<Vec<Self>> {
+        match self.grid_ring_unsafe(k) {
+            Err(H3ErrorCode::Pentagon) => Ok(self
+                .grid_disk_distances_safe(k)?
+                .into_iter()
+                .filter(|(_, distance)| *distance == k)
+                .map(|(h3, _)| h3)
+                .collect()),
+            result => result,
+        }
+    }
+
+    /// Produces the "hollow" ring of cells at exactly grid distance `k` from
+    /// `self`, in counter-clockwise order.
+    ///
+    /// Fails with [`H3ErrorCode::Pentagon`] if a pentagon is encountered, as
+    /// the output would otherwise be incorrect.
+    pub fn grid_ring_unsafe(&self, k: usize) -> Result<Vec<Self>> {
+        if !self.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        if k == 0 {
+            return Ok(vec![self.clone()]);
+        }
+
+        if self.is_pentagon() {
+            return Err(H3ErrorCode::Pentagon);
+        }
+
+        let mut origin = self.clone();
+        let mut rotations = 0;
+
+        for _ in 0..k {
+            (origin, rotations) = origin.neighbor_rotations(NEXT_RING_DIRECTION, rotations)?;
+            if origin.is_pentagon() {
+                return Err(H3ErrorCode::Pentagon);
+            }
+        }
+
+        let last = origin.clone();
+        let mut out = with_cell_capacity(k.saturating_mul(6), self.resolution())?;
+        out.push(origin.clone());
+
+        for (direction, dir) in DIRECTIONS.into_iter().enumerate() {
+            for pos in 0..k {
+                (origin, rotations) = origin.neighbor_rotations(dir, rotations)?;
+
+                // skip the very last index, it was already added; we do
+                // however need to traverse to it because of the pentagonal
+                // distortion check, below
+                if pos != k - 1 || direction != 5 {
+                    out.push(origin.clone());
+                    if origin.is_pentagon() {
+                        return Err(H3ErrorCode::Pentagon);
+                    }
+                }
+            }
+        }
+
+        // if we did not end up where we started, pentagonal distortion
+        // occurred and we should report failure
+        if last != origin {
+            return Err(H3ErrorCode::Pentagon);
+        }
+
+        Ok(out)
+    }
+
+    /// Produces the cells within grid distance `k` of `self` by breadth-first
+    /// search over cell neighbors. Slower than the spiral algorithm, but
+    /// correct in the presence of pentagons.
+    fn grid_disk_distances_safe(&self, k: usize) -> Result<Vec<(Self, usize)>> {
+        let mut distances = HashMap::new();
+        distances.insert(self.clone(), 0);
+
+        let mut out = vec![(self.clone(), 0)];
+        let mut frontier = vec![self.clone()];
+
+        for ring in 1..=k {
+            // the disk covers the whole globe
+            if frontier.is_empty() {
+                break;
+            }
+
+            let mut next = Vec::new();
+
+            for cell in &frontier {
+                for dir in [K, J, JK, I, IK, IJ] {
+                    let neighbor = match cell.neighbor_rotations(dir, 0) {
+                        Ok((neighbor, _)) => neighbor,
+                        // the deleted k direction of a pentagon
+                        Err(H3ErrorCode::Pentagon) => continue,
+                        Err(e) => return Err(e),
+                    };
+
+                    if !distances.contains_key(&neighbor) {
+                        distances.insert(neighbor.clone(), ring);
+                        out.push((neighbor.clone(), ring));
+                        next.push(neighbor);
+                    }
+                }
+            }
+
+            frontier = next;
+        }
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,101 @@
+//! Undirected edges between neighboring cells. An undirected edge index is
+//! the directed edge from the lower of its two cells to the higher, in
+//! [`H3_EDGE_MODE`], so that both directed edges between a pair of cells name
+//! the same undirected edge.
+use super::H3Index;
+use crate::{
+    cell_boundary::CellBoundary,
+    consts::{H3_DIRECTEDEDGE_MODE, H3_EDGE_MODE},
+    result::{H3ErrorCode, Result},
+};
+
+impl H3Index {
+    /// Produces the undirected edge between `self` and the neighboring cell
+    /// `other`, in either order.
+    ///
+    /// Fails with [`H3ErrorCode::NotNeighbors`] if the cells are not
+    /// neighbors.
+    pub fn cells_to_undirected_edge(&self, other: &H3Index) -> Result<H3Index> {
+        let edge = if self < other {
+            self.cells_to_directed_edge(other)?
+        } else {
+            other.cells_to_directed_edge(self)?
+        };
+
+        Ok(edge.set_mode(H3_EDGE_MODE))
+    }
+
+    /// Produces the undirected edge of the directed edge, which is the same
+    /// for both directions between a pair of cells.
+    ///
+    /// Fails with [`H3ErrorCode::DirEdgeInvalid`] if the index is not a valid
+    /// directed edge.
+    pub fn directed_edge_to_undirected(&self) -> Result<H3Index> {
+        if !self.is_valid_directed_edge() {
+            return Err(H3ErrorCode::DirEdgeInvalid);
+        }
+
+        let (origin, destination) = self.directed_edge_to_cells()?;
+        origin.cells_to_undirected_edge(&destination)
+    }
+
+    /// Produces the two directed edges of the undirected edge, starting with
+    /// the one from the lower cell.
+    ///
+    /// Fails with [`H3ErrorCode::UnDirEdgeInvalid`] if the index is not an
+    /// undirected edge.
+    pub fn undirected_edge_to_directed_edges(&self) -> Result<(H3Index, H3Index)> {
+        let edge = self.canonical_directed_edge()?;
+        let (origin, destination) = self.undirected_edge_to_cells()?;
+
+        Ok((edge, destination.cells_to_directed_edge(&origin)?))
+    }
+
+    /// Returns whether the index is a valid undirected edge, in canonical
+    /// form.
+    pub fn is_valid_undirected_edge(&self) -> bool {
+        self.canonical_directed_edge().is_ok()
+    }
+
+    /// Returns the two cells of the undirected edge, starting with the lower
+    /// one.
+    ///
+    /// Fails with [`H3ErrorCode::UnDirEdgeInvalid`] if the index is not an
+    /// undirected edge.
+    pub fn undirected_edge_to_cells(&self) -> Result<(H3Index, H3Index)> {
+        self.canonical_directed_edge()?
+            .directed_edge_to_cells()
+            .map_err(|_| H3ErrorCode::UnDirEdgeInvalid)
+    }
+
+    /// Finds the boundary of the undirected edge, oriented as the directed
+    /// edge from the lower cell.
+    ///
+    /// Fails with [`H3ErrorCode::UnDirEdgeInvalid`] if the index is not an
+    /// undirected edge.
+    pub fn undirected_edge_to_boundary(&self) -> Result<CellBoundary> {
+        self.canonical_directed_edge()?
+            .directed_edge_to_boundary()
+            .map_err(|_| H3ErrorCode::UnDirEdgeInvalid)
+    }
+
+    /// Returns the directed edge from the lower cell of the undirected edge.
+    /// Fails with [`H3ErrorCode::UnDirEdgeInvalid`] if the index is not in
+    /// undirected edge mode, does not name a valid edge, or is stored from
+    /// the higher of its two cells.
+    fn canonical_directed_edge(&self) -> Result<H3Index> {
+        if self.mode() != H3_EDGE_MODE {
+            return Err(H3ErrorCode::UnDirEdgeInvalid);
+        }
+
+        let edge = self.clone().set_mode(H3_DIRECTEDEDGE_MODE);
+        if !edge.is_valid_directed_edge() {
+            return Err(H3ErrorCode::UnDirEdgeInvalid);
+        }
+
+        match edge.directed_edge_to_cells() {
+            Ok((origin, destination)) if origin < destination => Ok(edge),
+            _ => Err(H3ErrorCode::UnDirEdgeInvalid),
+        }
+    }
+}
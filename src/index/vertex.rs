@@ -0,0 +1,279 @@
+//! Topological vertices of cells. A vertex index is the index of the cell
+//! owning the vertex in [`H3_VERTEX_MODE`], with the vertex number on that
+//! cell stored in the reserved bits. Each vertex is shared by three cells, and
+//! by convention is owned by the one with the lowest index.
+use super::{traversal::DIRECTIONS, H3Index};
+use crate::{
+    base_cell::BaseCell,
+    consts::{H3_CELL_MODE, H3_VERTEX_MODE, NUM_HEX_VERTS, NUM_PENT_VERTS},
+    direction::Direction,
+    face::{FaceIJK, Hexagon, Pentagon},
+    latlng::LatLng,
+    result::{H3ErrorCode, Result},
+};
+
+/// Hexagon direction to vertex number relationships (same face). Direction 0
+/// (center) is not used.
+const DIRECTION_TO_VERTEX_NUM_HEX: [Option<usize>; 7] =
+    [None, Some(3), Some(1), Some(2), Some(5), Some(4), Some(0)];
+
+/// Pentagon direction to vertex number relationships (same face). Directions
+/// 0 (center) and 1 (deleted k axes) are not used.
+const DIRECTION_TO_VERTEX_NUM_PENT: [Option<usize>; 7] =
+    [None, None, Some(1), Some(2), Some(4), Some(3), Some(0)];
+
+/// Hexagon direction to the reverse direction, as an index into
+/// [`DIRECTIONS`].
+const REV_NEIGHBOR_DIRECTIONS_HEX: [usize; 7] = [usize::MAX, 5, 3, 4, 1, 0, 2];
+
+/// Vertex number to hexagon direction relationships (same face).
+const VERTEX_NUM_TO_DIRECTION_HEX: [Direction; NUM_HEX_VERTS] = [
+    Direction::IJAxes,
+    Direction::JAxes,
+    Direction::JKAxes,
+    Direction::KAxes,
+    Direction::IKAxes,
+    Direction::IAxes,
+];
+
+/// Vertex number to pentagon direction relationships (same face).
+const VERTEX_NUM_TO_DIRECTION_PENT: [Direction; NUM_PENT_VERTS] = [
+    Direction::IJAxes,
+    Direction::JAxes,
+    Direction::JKAxes,
+    Direction::IKAxes,
+    Direction::IAxes,
+];
+
+impl H3Index {
+    /// Produces the index of vertex `vertex_num` of the cell, numbered
+    /// counter-clockwise. The same vertex has the same index whichever of the
+    /// three cells sharing it it is produced from.
+    ///
+    /// Fails with [`H3ErrorCode::CellInvalid`] if the index is not a valid
+    /// cell, and [`H3ErrorCode::Domain`] if `vertex_num` is not a vertex of
+    /// the cell.
+    pub fn cell_to_vertex(&self, vertex_num: usize) -> Result<H3Index> {
+        if !self.valid_cell() {
+            return Err(H3ErrorCode::CellInvalid);
+        }
+
+        let num_verts = self.num_verts();
+        let res = self.resolution();
+
+        if vertex_num >= num_verts {
+            return Err(H3ErrorCode::Domain);
+        }
+
+        // default the owner and vertex number to the input cell
+        let mut owner = self.clone();
+        let mut owner_vertex_num = vertex_num;
+
+        // determine the owner, looking at the three cells that share the
+        // vertex; if the cell is the center child of its parent, it will
+        // always have the lowest index of any neighbor, so we can skip this
+        if res == 0 || self.index_digit(res) != Direction::Center {
+            // get the left neighbor of the vertex, with its rotations
+            let left = self.direction_for_vertex_num(vertex_num)?;
+            let (left_neighbor, left_rotations) = self.neighbor_rotations(left, 0)?;
+            if left_neighbor < owner {
+                owner = left_neighbor.clone();
+            }
+
+            // as above, skip the right neighbor if the left is known lowest
+            if res == 0 || left_neighbor.index_digit(res) != Direction::Center {
+                // vertex - 1 is the right side, as vertex numbers are ccw
+                let right =
+                    self.direction_for_vertex_num((vertex_num + num_verts - 1) % num_verts)?;
+                let (right_neighbor, right_rotations) = self.neighbor_rotations(right, 0)?;
+
+                if right_neighbor < owner {
+                    let dir =
+                        self.direction_from_neighbor(&right_neighbor, right, right_rotations)?;
+                    owner_vertex_num = right_neighbor.vertex_num_for_direction(dir)?;
+                    owner = right_neighbor;
+                }
+            }
+
+            // for the left neighbor, we need the second vertex of the edge
+            if owner == left_neighbor {
+                let dir = self.direction_from_neighbor(&left_neighbor, left, left_rotations)?;
+                owner_vertex_num =
+                    (left_neighbor.vertex_num_for_direction(dir)? + 1) % left_neighbor.num_verts();
+            }
+        }
+
+        Ok(owner
+            .set_mode(H3_VERTEX_MODE)
+            .set_reserved(owner_vertex_num))
+    }
+
+    /// Produces the indexes of all the vertices of the cell, in
+    /// counter-clockwise order: six for a hexagon and five for a pentagon.
+    ///
+    /// Fails with [`H3ErrorCode::CellInvalid`] if the index is not a valid
+    /// cell.
+    pub fn cell_to_vertexes(&self) -> Result<Vec<H3Index>> {
+        (0..self.num_verts())
+            .map(|vertex_num| self.cell_to_vertex(vertex_num))
+            .collect()
+    }
+
+    /// Determines the spherical coordinates of the vertex.
+    ///
+    /// Fails with [`H3ErrorCode::Domain`] if the index is not a vertex, or its
+    /// vertex number is out of range for its owner.
+    pub fn vertex_to_latlng(&self) -> Result<LatLng> {
+        let (owner, vertex_num) = self.vertex_owner()?;
+
+        // get the single vertex from the boundary
+        let res = owner.resolution();
+        let boundary = if owner.is_pentagon() {
+            FaceIJK::<Pentagon>::try_from(&owner)?.to_cell_boundary(res, vertex_num, 1)?
+        } else {
+            FaceIJK::<Hexagon>::try_from(&owner)?.to_cell_boundary(res, vertex_num, 1)?
+        };
+
+        Ok(boundary.verts()[0])
+    }
+
+    /// Returns whether the index is a valid vertex, with a valid owner that
+    /// is the canonical owner of the vertex.
+    pub fn is_valid_vertex(&self) -> bool {
+        let Ok((owner, vertex_num)) = self.vertex_owner() else {
+            return false;
+        };
+
+        // the easiest way to ensure that the owner and vertex number are
+        // valid, and that the vertex is canonical, is to recreate and compare
+        owner.valid_cell() && owner.cell_to_vertex(vertex_num).as_ref() == Ok(self)
+    }
+
+    /// Returns the owning cell and vertex number of the vertex. Fails with
+    /// [`H3ErrorCode::Domain`] if the index is not a vertex or the vertex
+    /// number is not a vertex of the owner.
+    fn vertex_owner(&self) -> Result<(H3Index, usize)> {
+        if self.mode() != H3_VERTEX_MODE {
+            return Err(H3ErrorCode::Domain);
+        }
+
+        let owner = self.clone().set_mode(H3_CELL_MODE).set_reserved(0);
+        let vertex_num = self.reserved();
+        if vertex_num >= owner.num_verts() {
+            return Err(H3ErrorCode::Domain);
+        }
+
+        Ok((owner, vertex_num))
+    }
+
+    /// Returns the number of vertices of the cell.
+    fn num_verts(&self) -> usize {
+        if self.is_pentagon() {
+            NUM_PENT_VERTS
+        } else {
+            NUM_HEX_VERTS
+        }
+    }
+
+    /// Finds the direction from `neighbor` back to `self`, given the
+    /// direction `dir` and rotations from `self` to `neighbor`.
+    fn direction_from_neighbor(
+        &self,
+        neighbor: &H3Index,
+        dir: Direction,
+        rotations: usize,
+    ) -> Result<Direction> {
+        if neighbor.is_pentagon() {
+            neighbor
+                .direction_for_neighbor(self)
+                .ok_or(H3ErrorCode::Failed)
+        } else {
+            let reverse = REV_NEIGHBOR_DIRECTIONS_HEX[usize::from(dir)];
+            Ok(DIRECTIONS[(reverse + rotations) % NUM_HEX_VERTS])
+        }
+    }
+
+    /// Returns the direction to the neighbor sharing the cell's edge that
+    /// starts at vertex `vertex_num`. Fails with [`H3ErrorCode::Domain`] if
+    /// `vertex_num` is not a vertex of the cell.
+    pub(crate) fn direction_for_vertex_num(&self, vertex_num: usize) -> Result<Direction> {
+        let num_verts = self.num_verts();
+        if vertex_num >= num_verts {
+            return Err(H3ErrorCode::Domain);
+        }
+
+        // find the appropriate direction, rotating cw if necessary
+        let v = (vertex_num + self.vertex_rotations()?) % num_verts;
+        Ok(if self.is_pentagon() {
+            VERTEX_NUM_TO_DIRECTION_PENT[v]
+        } else {
+            VERTEX_NUM_TO_DIRECTION_HEX[v]
+        })
+    }
+
+    /// Returns the number of counter-clockwise 60 degree rotations of the
+    /// cell's vertex numbers compared to the directional layout of its
+    /// neighbors.
+    pub(crate) fn vertex_rotations(&self) -> Result<usize> {
+        // get the face and other info for the origin
+        let fijk = self.to_face_ijk()?;
+        let base_cell = BaseCell::try_from(self.base_cell())?;
+        let leading_digit = self.leading_non_zero_digit();
+
+        // get the base cell face
+        let base_fijk = base_cell.to_face_ijk();
+        let mut ccw_rot60 = base_cell
+            .ccw_rot60(fijk.face)
+            .ok_or(H3ErrorCode::CellInvalid)? as usize;
+
+        if base_cell.is_pentagon() {
+            // find the appropriate direction-to-face mapping
+            let dir_faces = base_cell
+                .pentagon_direction_faces()
+                .ok_or(H3ErrorCode::CellInvalid)?;
+            let face_in_dir = |dir: Direction| dir_faces[usize::from(dir) - 2];
+
+            // additional ccw rotation for polar neighbors or ik neighbors
+            if fijk.face != base_fijk.face
+                && (base_cell.is_polar_pentagon() || fijk.face == face_in_dir(Direction::IKAxes))
+            {
+                ccw_rot60 = (ccw_rot60 + 1) % 6;
+            }
+
+            // check whether the cell crosses a deleted pentagon subsequence
+            if leading_digit == Direction::JKAxes && fijk.face == face_in_dir(Direction::IKAxes) {
+                // crosses from jk to ik: rotate cw
+                ccw_rot60 = (ccw_rot60 + 5) % 6;
+            } else if leading_digit == Direction::IKAxes
+                && fijk.face == face_in_dir(Direction::JKAxes)
+            {
+                // crosses from ik to jk: rotate ccw
+                ccw_rot60 = (ccw_rot60 + 1) % 6;
+            }
+        }
+
+        Ok(ccw_rot60)
+    }
+
+    /// Returns the number of the first topological vertex of the cell's edge
+    /// in direction `dir`, in counter-clockwise order. Fails with
+    /// [`H3ErrorCode::Domain`] for the center direction, invalid directions
+    /// and the deleted k direction of a pentagon.
+    pub(crate) fn vertex_num_for_direction(&self, dir: Direction) -> Result<usize> {
+        let (table, num_verts) = if self.is_pentagon() {
+            (&DIRECTION_TO_VERTEX_NUM_PENT, NUM_PENT_VERTS)
+        } else {
+            (&DIRECTION_TO_VERTEX_NUM_HEX, NUM_HEX_VERTS)
+        };
+
+        let vertex_num = table
+            .get(usize::from(dir))
+            .copied()
+            .flatten()
+            .ok_or(H3ErrorCode::Domain)?;
+
+        // find the appropriate vertex, rotating ccw if necessary
+        let rotations = self.vertex_rotations()?;
+        Ok((vertex_num + num_verts - rotations) % num_verts)
+    }
+}
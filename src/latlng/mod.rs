@@ -1,5 +1,183 @@
-#[derive(Debug, PartialEq, PartialOrd)]
+use derive_new::new;
+
+use crate::{
+    consts::{EARTH_RADIUS_KM, EPSILON, EPSILON_RAD, M_180_PI, M_2PI, M_PI, M_PI_180, M_PI_2},
+    index::H3Index,
+    result::Result,
+};
+
+/// Latitude/longitude in radians.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, new)]
 pub struct LatLng {
     pub lat: f64,
     pub lng: f64,
 }
+
+impl LatLng {
+    /// Creates a [`LatLng`] from a latitude and longitude in degrees.
+    pub fn from_degrees(lat: f64, lng: f64) -> Self {
+        Self::new(degs_to_rads(lat), degs_to_rads(lng))
+    }
+
+    /// Returns the latitude in degrees.
+    pub fn lat_degrees(&self) -> f64 {
+        rads_to_degs(self.lat)
+    }
+
+    /// Returns the longitude in degrees.
+    pub fn lng_degrees(&self) -> f64 {
+        rads_to_degs(self.lng)
+    }
+
+    /// Returns whether both coordinates are finite numbers.
+    pub fn is_finite(&self) -> bool {
+        self.lat.is_finite() && self.lng.is_finite()
+    }
+
+    /// Whether two coordinates are within [`EPSILON_RAD`] of each other in
+    /// both latitude and longitude.
+    pub fn almost_equals(&self, other: &LatLng) -> bool {
+        (self.lat - other.lat).abs() < EPSILON_RAD && (self.lng - other.lng).abs() < EPSILON_RAD
+    }
+
+    /// Determines the azimuth to `other` from `self` in radians.
+    pub fn azimuth_rads(&self, other: &LatLng) -> f64 {
+        (other.lat.cos() * (other.lng - self.lng).sin()).atan2(
+            self.lat.cos() * other.lat.sin()
+                - self.lat.sin() * other.lat.cos() * (other.lng - self.lng).cos(),
+        )
+    }
+
+    /// Calculates the great circle distance in radians between `self` and
+    /// `other`, using the haversine formula.
+    pub fn great_circle_distance_rads(&self, other: &LatLng) -> f64 {
+        let sin_lat = ((other.lat - self.lat) * 0.5).sin();
+        let sin_lng = ((other.lng - self.lng) * 0.5).sin();
+
+        let a = sin_lat * sin_lat + self.lat.cos() * other.lat.cos() * sin_lng * sin_lng;
+
+        2. * a.sqrt().atan2((1. - a).sqrt())
+    }
+
+    /// Calculates the great circle distance in kilometers between `self` and
+    /// `other`.
+    pub fn great_circle_distance_km(&self, other: &LatLng) -> f64 {
+        self.great_circle_distance_rads(other) * EARTH_RADIUS_KM
+    }
+
+    /// Computes the point on the sphere a specified azimuth and distance
+    /// from `self`.
+    pub fn az_distance_rads(&self, az: f64, distance: f64) -> LatLng {
+        if distance < EPSILON {
+            return *self;
+        }
+
+        let az = pos_angle_rads(az);
+
+        // check for due north/south azimuth
+        if az < EPSILON || (az - M_PI).abs() < EPSILON {
+            let lat = if az < EPSILON {
+                // due north
+                self.lat + distance
+            } else {
+                // due south
+                self.lat - distance
+            };
+
+            return pole_or(lat, || constrain_lng(self.lng));
+        }
+
+        // not due north or south
+        let sinlat = (self.lat.sin() * distance.cos() + self.lat.cos() * distance.sin() * az.cos())
+            .clamp(-1., 1.);
+        let lat = sinlat.asin();
+
+        pole_or(lat, || {
+            let sinlng = (az.sin() * distance.sin() / lat.cos()).clamp(-1., 1.);
+            let coslng =
+                ((distance.cos() - self.lat.sin() * lat.sin()) / self.lat.cos() / lat.cos())
+                    .clamp(-1., 1.);
+
+            constrain_lng(self.lng + sinlng.atan2(coslng))
+        })
+    }
+
+    /// Finds the cell containing `self` at resolution `res`.
+    pub fn to_cell(&self, res: usize) -> Result<H3Index> {
+        H3Index::from_latlng(self, res)
+    }
+}
+
+/// Converts an angle in degrees to radians.
+pub fn degs_to_rads(degrees: f64) -> f64 {
+    degrees * M_PI_180
+}
+
+/// Converts an angle in radians to degrees.
+pub fn rads_to_degs(radians: f64) -> f64 {
+    radians * M_180_PI
+}
+
+/// Makes sure longitudes are in the proper bounds.
+pub fn constrain_lng(mut lng: f64) -> f64 {
+    while lng > M_PI {
+        lng -= M_2PI;
+    }
+
+    while lng < -M_PI {
+        lng += M_2PI;
+    }
+
+    lng
+}
+
+/// Computes the area in radians^2 of the spherical triangle with vertices
+/// `a`, `b` and `c`, using L'Huilier's theorem.
+pub fn triangle_area_rads2(a: &LatLng, b: &LatLng, c: &LatLng) -> f64 {
+    triangle_edge_lengths_to_area(
+        a.great_circle_distance_rads(b),
+        b.great_circle_distance_rads(c),
+        c.great_circle_distance_rads(a),
+    )
+}
+
+/// Computes the area in radians^2 of a spherical triangle from the lengths of
+/// its edges in radians.
+fn triangle_edge_lengths_to_area(a: f64, b: f64, c: f64) -> f64 {
+    let s = (a + b + c) / 2.;
+
+    let a = (s - a) / 2.;
+    let b = (s - b) / 2.;
+    let c = (s - c) / 2.;
+    let s = s / 2.;
+
+    4. * (s.tan() * a.tan() * b.tan() * c.tan()).sqrt().atan()
+}
+
+/// Snaps `lat` to the nearest pole if it is within [`EPSILON`] of one,
+/// otherwise pairs it with the longitude computed by `lng`.
+fn pole_or<F>(lat: f64, lng: F) -> LatLng
+where
+    F: FnOnce() -> f64,
+{
+    if (lat - M_PI_2).abs() < EPSILON {
+        // north pole
+        LatLng::new(M_PI_2, 0.)
+    } else if (lat + M_PI_2).abs() < EPSILON {
+        // south pole
+        LatLng::new(-M_PI_2, 0.)
+    } else {
+        LatLng::new(lat, lng())
+    }
+}
+
+/// Normalizes radians to a value between 0.0 and two PI.
+pub(crate) fn pos_angle_rads(rads: f64) -> f64 {
+    let tmp = if rads < 0. { rads + M_2PI } else { rads };
+
+    if rads >= M_2PI {
+        tmp - M_2PI
+    } else {
+        tmp
+    }
+}
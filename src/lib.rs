@@ -1,12 +1,16 @@
 pub mod base_cell;
 pub mod bounding_box;
+pub mod cell_boundary;
 pub(crate) mod consts;
 pub mod coordinate;
 pub mod direction;
 pub mod face;
+pub mod geometry;
 pub mod index;
 pub mod latlng;
+pub mod polygon;
 pub mod result;
+pub(crate) mod vertex_graph;
 
 pub use consts::*;
 pub use index::H3Index;
@@ -14,5 +18,5 @@ pub use index::H3Index;
 pub const MAX_CELL_BOUNDARY_VERTS: usize = 10;
 
 pub fn is_resolution_class_iii(res: usize) -> bool {
-    res % 2 > 0
+    !res.is_multiple_of(2)
 }
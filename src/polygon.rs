@@ -0,0 +1,428 @@
+use std::collections::HashSet;
+
+use derive_new::new;
+
+use crate::{
+    bounding_box::{line_hex_estimate, BoundingBox},
+    consts::{MAX_H3_RES, M_2PI, M_PI},
+    index::H3Index,
+    latlng::{constrain_lng, LatLng},
+    result::{H3ErrorCode, Result},
+    vertex_graph::VertexGraph,
+};
+
+/// Extra cells added to the estimate of [`max_polygon_to_cells_size`]. When
+/// the polygon is very small, near an icosahedron edge and at an odd
+/// resolution, the line tracing needs a larger buffer than the estimator
+/// provides.
+const POLYGON_TO_CELLS_BUFFER: usize = 12;
+
+/// Closed loop of coordinates. The last vertex is implicitly connected back to
+/// the first.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, new)]
+pub struct GeoLoop {
+    /// vertices of the loop
+    pub verts: Vec<LatLng>,
+}
+
+/// Polygon made of an outer loop and zero or more holes.
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, new)]
+pub struct GeoPolygon {
+    /// exterior boundary of the polygon
+    pub geoloop: GeoLoop,
+    /// interior boundaries of the polygon
+    pub holes: Vec<GeoLoop>,
+}
+
+/// Bounding boxes of a [`GeoPolygon`], as produced by [`GeoPolygon::bboxes`].
+#[derive(Clone, Debug, Default, PartialEq, PartialOrd, new)]
+pub struct PolygonBBoxes {
+    /// bounding box of the outer loop
+    pub outer: BoundingBox,
+    /// bounding boxes of the holes, in the order of the holes
+    pub holes: Vec<BoundingBox>,
+}
+
+impl GeoLoop {
+    /// Creates the bounding box of the loop.
+    pub fn bbox(&self) -> BoundingBox {
+        BoundingBox::from_loop(&self.verts)
+    }
+
+    /// Iterates over the edges of the loop, including the closing edge from
+    /// the last vertex back to the first.
+    fn edges(&self) -> impl Iterator<Item = (&LatLng, &LatLng)> {
+        let next = self.verts.iter().cycle().skip(1);
+        self.verts.iter().zip(next)
+    }
+
+    /// Whether the loop contains `coord`, given the bounding box of the loop.
+    ///
+    /// Uses a ray casting algorithm along lines of latitude. Loops crossing
+    /// the antimeridian, as indicated by `bbox`, are handled by shifting
+    /// negative longitudes east by 360 degrees. Points exactly on an edge are
+    /// biased west, so that cells sharing an edge are not both contained.
+    pub fn contains(&self, bbox: &BoundingBox, coord: &LatLng) -> bool {
+        // fail fast if we're outside the bounding box
+        if !bbox.contains(coord) {
+            return false;
+        }
+
+        let is_transmeridian = bbox.is_transmeridian();
+        let normalize = |lng: f64| {
+            if is_transmeridian && lng < 0. {
+                lng + M_2PI
+            } else {
+                lng
+            }
+        };
+
+        let lat = coord.lat;
+        let mut lng = normalize(coord.lng);
+        let mut contains = false;
+
+        for (a, b) in self.edges() {
+            // ray casting requires the second point to always be higher than
+            // the first, so swap if needed
+            let (a, b) = if a.lat > b.lat { (b, a) } else { (a, b) };
+
+            // if we're totally above or below the latitude range, the test
+            // ray cannot intersect the line segment
+            if lat < a.lat || lat > b.lat {
+                continue;
+            }
+
+            let a_lng = normalize(a.lng);
+            let b_lng = normalize(b.lng);
+
+            // rays are cast in the longitudinal direction; in case a point
+            // exactly matches, bias westerly to decide tiebreakers
+            if a_lng == lng || b_lng == lng {
+                lng -= f64::EPSILON;
+            }
+
+            // for the latitude of the point, compute the longitude of the
+            // point that lies on the line segment defined by a and b, by
+            // traversing the same fraction in longitude as in latitude
+            let ratio = (lat - a.lat) / (b.lat - a.lat);
+            let test_lng = normalize(a_lng + (b_lng - a_lng) * ratio);
+
+            // intersection of the ray
+            if test_lng > lng {
+                contains = !contains;
+            }
+        }
+
+        contains
+    }
+
+    /// Whether the vertices of the loop are in clockwise order, as for the
+    /// holes produced by [`cells_to_multi_polygon`].
+    pub fn is_clockwise(&self) -> bool {
+        self.is_clockwise_normalized(false)
+    }
+
+    /// Determines the winding order from the sign of the signed area of the
+    /// loop. If an arc of more than 180 degrees longitude is found, starts
+    /// over with negative longitudes shifted east by 360 degrees.
+    fn is_clockwise_normalized(&self, is_transmeridian: bool) -> bool {
+        let normalize = |lng: f64| {
+            if is_transmeridian && lng < 0. {
+                lng + M_2PI
+            } else {
+                lng
+            }
+        };
+
+        let mut sum = 0.;
+        for (a, b) in self.edges() {
+            if !is_transmeridian && (a.lng - b.lng).abs() > M_PI {
+                return self.is_clockwise_normalized(true);
+            }
+
+            sum += (normalize(b.lng) - normalize(a.lng)) * (b.lat + a.lat);
+        }
+
+        sum > 0.
+    }
+}
+
+impl GeoPolygon {
+    /// Creates the bounding boxes of the outer loop and each of the holes.
+    pub fn bboxes(&self) -> PolygonBBoxes {
+        PolygonBBoxes {
+            outer: self.geoloop.bbox(),
+            holes: self.holes.iter().map(GeoLoop::bbox).collect(),
+        }
+    }
+
+    /// Whether the polygon contains `coord`, that is whether the outer loop
+    /// contains it and none of the holes do. `bboxes` are the bounding boxes
+    /// of the polygon as produced by [`GeoPolygon::bboxes`]; the bounding box
+    /// of a hole missing from `bboxes` is computed on demand.
+    pub fn contains(&self, bboxes: &PolygonBBoxes, coord: &LatLng) -> bool {
+        // fail fast if we're outside the outer loop
+        if !self.geoloop.contains(&bboxes.outer, coord) {
+            return false;
+        }
+
+        !self
+            .holes
+            .iter()
+            .enumerate()
+            .any(|(i, hole)| match bboxes.holes.get(i) {
+                Some(bbox) => hole.contains(bbox, coord),
+                None => hole.contains(&hole.bbox(), coord),
+            })
+    }
+}
+
+/// Returns an upper bound on the number of cells [`polygon_to_cells`] produces
+/// for `polygon` at resolution `res`.
+pub fn max_polygon_to_cells_size(polygon: &GeoPolygon, res: usize) -> Result<usize> {
+    let estimate = polygon.geoloop.bbox().bbox_hex_estimate(res)?;
+
+    // this assumes that the number of vertices is usually less than the
+    // number of cells, but when it's wrong this keeps it from failing
+    let total_verts = polygon.geoloop.verts.len()
+        + polygon
+            .holes
+            .iter()
+            .map(|hole| hole.verts.len())
+            .sum::<usize>();
+
+    Ok(estimate.max(total_verts) + POLYGON_TO_CELLS_BUFFER)
+}
+
+/// Produces the cells at resolution `res` whose centers are contained by
+/// `polygon`.
+///
+/// The cells along the edges of all the loops of the polygon are traced
+/// first, and then flood filled inward, keeping those cells whose centers are
+/// within the polygon. Fails with [`H3ErrorCode::ResDomain`] if `res` is not a
+/// valid resolution.
+pub fn polygon_to_cells(polygon: &GeoPolygon, res: usize) -> Result<Vec<H3Index>> {
+    if res > MAX_H3_RES as usize {
+        return Err(H3ErrorCode::ResDomain);
+    }
+
+    let bboxes = polygon.bboxes();
+    let mut search = edge_cells(polygon, res)?;
+    let mut found = HashSet::new();
+    let mut cells = Vec::new();
+
+    while !search.is_empty() {
+        let mut next = Vec::new();
+
+        for cell in &search {
+            for neighbor in cell.grid_disk(1)? {
+                if found.contains(&neighbor) || !polygon.contains(&bboxes, &neighbor.to_latlng()?) {
+                    continue;
+                }
+
+                found.insert(neighbor.clone());
+                cells.push(neighbor.clone());
+                next.push(neighbor);
+            }
+        }
+
+        search = next;
+    }
+
+    Ok(cells)
+}
+
+/// Dissolves a set of cells of the same resolution into the polygons
+/// outlining them. Outer loops are in counter-clockwise order, and holes in
+/// clockwise order.
+///
+/// Fails with [`H3ErrorCode::ResMismatch`] if the cells are not all of the
+/// same resolution, and [`H3ErrorCode::Failed`] if a hole cannot be assigned
+/// to a polygon, which is possible with invalid input.
+pub fn cells_to_multi_polygon(cells: &[H3Index]) -> Result<Vec<GeoPolygon>> {
+    let loops = VertexGraph::from_cells(cells)?.into_loops();
+
+    // with a single loop there is nothing to normalize
+    if loops.len() <= 1 {
+        return Ok(loops
+            .into_iter()
+            .map(|geoloop| GeoPolygon::new(geoloop, Vec::new()))
+            .collect());
+    }
+
+    // move the inner loops aside and make a polygon of each outer loop
+    let (holes, outers): (Vec<_>, Vec<_>) = loops.into_iter().partition(GeoLoop::is_clockwise);
+    let bboxes: Vec<_> = outers.iter().map(GeoLoop::bbox).collect();
+    let mut polygons: Vec<_> = outers
+        .into_iter()
+        .map(|geoloop| GeoPolygon::new(geoloop, Vec::new()))
+        .collect();
+
+    for hole in holes {
+        let index = find_polygon_for_hole(&hole, &polygons, &bboxes).ok_or(H3ErrorCode::Failed)?;
+        polygons[index].holes.push(hole);
+    }
+
+    Ok(polygons)
+}
+
+/// Finds the index of the polygon whose outer loop most immediately contains
+/// `hole`. `bboxes` are the bounding boxes of the outer loops.
+fn find_polygon_for_hole(
+    hole: &GeoLoop,
+    polygons: &[GeoPolygon],
+    bboxes: &[BoundingBox],
+) -> Option<usize> {
+    // early exit with only one polygon
+    if polygons.len() == 1 {
+        return Some(0);
+    }
+
+    // the loops are guaranteed not to overlap, so just test the first point
+    let vertex = hole.verts.first()?;
+    let candidates: Vec<_> = (0..polygons.len())
+        .filter(|&i| polygons[i].geoloop.contains(&bboxes[i], vertex))
+        .collect();
+
+    // multiple candidates must be nested inside each other; the innermost
+    // one is contained by the most other candidates
+    candidates
+        .iter()
+        .rev()
+        .max_by_key(|&&i| {
+            let first = &polygons[i].geoloop.verts[0];
+            candidates
+                .iter()
+                .filter(|&&j| j != i && polygons[j].geoloop.contains(&bboxes[j], first))
+                .count()
+        })
+        .copied()
+}
+
+/// Traces the cells along the edges of all the loops of the polygon, without
+/// duplicates.
+fn edge_cells(polygon: &GeoPolygon, res: usize) -> Result<Vec<H3Index>> {
+    let mut seen = HashSet::new();
+    let mut cells = Vec::new();
+
+    for geoloop in core::iter::once(&polygon.geoloop).chain(&polygon.holes) {
+        for (origin, destination) in geoloop.edges() {
+            let estimate = line_hex_estimate(origin, destination, res)?;
+
+            // interpolate the short way around across the antimeridian
+            let mut dest_lng = destination.lng;
+            if dest_lng - origin.lng > M_PI {
+                dest_lng -= M_2PI;
+            } else if origin.lng - dest_lng > M_PI {
+                dest_lng += M_2PI;
+            }
+
+            for j in 0..estimate {
+                let t = j as f64 / estimate as f64;
+                let point = LatLng::new(
+                    origin.lat + (destination.lat - origin.lat) * t,
+                    constrain_lng(origin.lng + (dest_lng - origin.lng) * t),
+                );
+
+                let cell = point.to_cell(res)?;
+                if seen.insert(cell.clone()) {
+                    cells.push(cell);
+                }
+            }
+        }
+    }
+
+    Ok(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(lat: f64, lng: f64, half: f64) -> GeoLoop {
+        GeoLoop::new(vec![
+            LatLng::new(lat + half, constrain_lng(lng + half)),
+            LatLng::new(lat + half, constrain_lng(lng - half)),
+            LatLng::new(lat - half, constrain_lng(lng - half)),
+            LatLng::new(lat - half, constrain_lng(lng + half)),
+        ])
+    }
+
+    #[test]
+    fn loop_contains() {
+        let geoloop = square(0.5, 0.5, 0.1);
+        let bbox = geoloop.bbox();
+
+        assert!(geoloop.contains(&bbox, &LatLng::new(0.5, 0.5)));
+        assert!(geoloop.contains(&bbox, &LatLng::new(0.45, 0.58)));
+        assert!(!geoloop.contains(&bbox, &LatLng::new(0.5, 0.7)));
+        assert!(!geoloop.contains(&bbox, &LatLng::new(0.7, 0.5)));
+    }
+
+    #[test]
+    fn loop_contains_triangle() {
+        let geoloop = GeoLoop::new(vec![
+            LatLng::new(0., 0.),
+            LatLng::new(1., 0.),
+            LatLng::new(0., 1.),
+        ]);
+        let bbox = geoloop.bbox();
+
+        assert!(geoloop.contains(&bbox, &LatLng::new(0.2, 0.2)));
+        assert!(!geoloop.contains(&bbox, &LatLng::new(0.8, 0.8)));
+    }
+
+    #[test]
+    fn loop_contains_transmeridian() {
+        let geoloop = square(0.2, M_PI, 0.1);
+        let bbox = geoloop.bbox();
+        assert!(bbox.is_transmeridian());
+
+        assert!(geoloop.contains(&bbox, &LatLng::new(0.2, M_PI - 0.05)));
+        assert!(geoloop.contains(&bbox, &LatLng::new(0.2, -M_PI + 0.05)));
+        assert!(!geoloop.contains(&bbox, &LatLng::new(0.2, 0.)));
+        assert!(!geoloop.contains(&bbox, &LatLng::new(0.2, M_PI - 0.2)));
+    }
+
+    #[test]
+    fn loop_is_clockwise() {
+        let mut geoloop = square(0.5, 0.5, 0.1);
+        assert!(!geoloop.is_clockwise());
+        geoloop.verts.reverse();
+        assert!(geoloop.is_clockwise());
+
+        let mut geoloop = square(0.2, M_PI, 0.1);
+        assert!(!geoloop.is_clockwise());
+        geoloop.verts.reverse();
+        assert!(geoloop.is_clockwise());
+    }
+
+    #[test]
+    fn polygon_contains_holes() {
+        let polygon = GeoPolygon::new(square(0.5, 0.5, 0.2), vec![square(0.5, 0.5, 0.05)]);
+        let bboxes = polygon.bboxes();
+        assert_eq!(1, bboxes.holes.len());
+
+        assert!(polygon.contains(&bboxes, &LatLng::new(0.5, 0.6)));
+        assert!(!polygon.contains(&bboxes, &LatLng::new(0.5, 0.5)));
+        assert!(!polygon.contains(&bboxes, &LatLng::new(0.5, 0.8)));
+
+        // the bounding box of a hole is computed when it is not given
+        let outer_only = PolygonBBoxes::new(bboxes.outer, vec![]);
+        assert!(polygon.contains(&outer_only, &LatLng::new(0.5, 0.6)));
+        assert!(!polygon.contains(&outer_only, &LatLng::new(0.5, 0.5)));
+    }
+
+    #[test]
+    fn max_size_covers_vertices() {
+        let polygon = GeoPolygon::new(square(0.5, 0.5, 1e-6), vec![]);
+        assert_eq!(
+            Ok(4 + POLYGON_TO_CELLS_BUFFER),
+            max_polygon_to_cells_size(&polygon, 0)
+        );
+        assert_eq!(
+            Err(H3ErrorCode::ResDomain),
+            max_polygon_to_cells_size(&polygon, 16)
+        );
+    }
+}
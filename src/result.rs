@@ -2,6 +2,7 @@ pub type Result<T> = core::result::Result<T, H3ErrorCode>;
 
 pub type H3Error = u32;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum H3ErrorCode {
     /// no error
     Success,
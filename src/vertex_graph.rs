@@ -0,0 +1,280 @@
+//! Graph of directed edges between cell boundary vertices, used to dissolve a
+//! set of cells into the loops outlining them.
+use std::collections::HashSet;
+
+use derive_new::new;
+
+use crate::{
+    consts::EPSILON_RAD,
+    index::H3Index,
+    latlng::LatLng,
+    polygon::GeoLoop,
+    result::{H3ErrorCode, Result},
+};
+
+/// Minimum number of buckets in a graph built from cells.
+const MIN_BUCKETS: usize = 6;
+
+/// Directed edge between two vertices.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub(crate) struct VertexNode {
+    /// origin vertex
+    pub from: LatLng,
+    /// destination vertex
+    pub to: LatLng,
+}
+
+/// Hash table of directed edges, bucketed by origin vertex. Vertices are
+/// compared with [`LatLng::almost_equals`], so that the same vertex computed
+/// from two neighboring cells is matched.
+///
+/// Vertices are hashed on their coordinates quantized to [`EPSILON_RAD`].
+/// Two almost equal vertices can still straddle a quantization step, so
+/// lookups also search the buckets of the adjacent steps.
+#[derive(Clone, Debug)]
+pub(crate) struct VertexGraph {
+    /// edges bucketed by the hash of their origin vertex
+    buckets: Vec<Vec<VertexNode>>,
+    /// number of edges in the graph
+    size: usize,
+}
+
+impl VertexGraph {
+    /// Creates an empty graph with `num_buckets` buckets.
+    pub fn new(num_buckets: usize) -> Self {
+        Self {
+            buckets: vec![Vec::new(); num_buckets],
+            size: 0,
+        }
+    }
+
+    /// Creates the graph of the outline of a set of cells of the same
+    /// resolution, made of the boundaries of the edges between the cells and
+    /// their neighbors outside of the set.
+    ///
+    /// Edges shared by two cells of the set are skipped by their topology
+    /// rather than matched by their vertices: where an edge crosses an
+    /// icosahedron edge, each cell computes its distortion vertex on its own
+    /// face, and the two can differ by more than [`EPSILON_RAD`].
+    ///
+    /// Fails with [`H3ErrorCode::ResMismatch`] if the cells are not all of the
+    /// same resolution.
+    pub fn from_cells(cells: &[H3Index]) -> Result<Self> {
+        let Some(first) = cells.first() else {
+            return Ok(Self::new(0));
+        };
+
+        let res = first.resolution();
+        if cells.iter().any(|cell| cell.resolution() != res) {
+            return Err(H3ErrorCode::ResMismatch);
+        }
+
+        let set: HashSet<_> = cells.iter().collect();
+        let mut graph = Self::new(cells.len().max(MIN_BUCKETS));
+
+        for cell in cells {
+            for edge in cell.origin_to_directed_edges()? {
+                if set.contains(&edge.destination()?) {
+                    continue;
+                }
+
+                // the edge boundary runs counter-clockwise around the cell,
+                // including any distortion vertex
+                let boundary = edge.directed_edge_to_boundary()?;
+                for pair in boundary.verts().windows(2) {
+                    graph.add(&pair[0], &pair[1]);
+                }
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns the number of edges in the graph.
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the graph has no edges.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hashes a quantized vertex to a bucket.
+    fn bucket(&self, (lat, lng): (i64, i64)) -> usize {
+        let hash = (lat as u64)
+            .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+            .rotate_left(31)
+            ^ lng as u64;
+
+        (hash.wrapping_mul(0x9e37_79b9_7f4a_7c15) % self.buckets.len() as u64) as usize
+    }
+
+    /// Returns the bucket `vertex` is stored in.
+    fn hash_vertex(&self, vertex: &LatLng) -> usize {
+        self.bucket(quantize(vertex))
+    }
+
+    /// Returns the buckets that may hold a vertex almost equal to `vertex`,
+    /// that is those of its quantization step and the adjacent ones.
+    fn candidate_buckets(&self, vertex: &LatLng) -> impl Iterator<Item = usize> + '_ {
+        let (lat, lng) = quantize(vertex);
+
+        (-1..=1).flat_map(move |d_lat| {
+            (-1..=1).map(move |d_lng| self.bucket((lat + d_lat, lng + d_lng)))
+        })
+    }
+
+    /// Adds an edge to the graph, unless it already exists.
+    pub fn add(&mut self, from: &LatLng, to: &LatLng) {
+        if self.find_edge(from, to).is_some() {
+            return;
+        }
+
+        let index = self.hash_vertex(from);
+        self.buckets[index].push(VertexNode::new(*from, *to));
+        self.size += 1;
+    }
+
+    /// Removes an edge previously found in the graph.
+    pub fn remove(&mut self, node: &VertexNode) {
+        let index = self.hash_vertex(&node.from);
+        let bucket = &mut self.buckets[index];
+
+        if let Some(i) = bucket.iter().position(|n| n == node) {
+            bucket.remove(i);
+            self.size -= 1;
+        }
+    }
+
+    /// Finds the edge from `from` to `to`.
+    pub fn find_edge(&self, from: &LatLng, to: &LatLng) -> Option<VertexNode> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.candidate_buckets(from)
+            .flat_map(|index| &self.buckets[index])
+            .find(|node| node.from.almost_equals(from) && node.to.almost_equals(to))
+            .copied()
+    }
+
+    /// Finds an edge starting at `from`.
+    pub fn find_vertex(&self, from: &LatLng) -> Option<VertexNode> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.candidate_buckets(from)
+            .flat_map(|index| &self.buckets[index])
+            .find(|node| node.from.almost_equals(from))
+            .copied()
+    }
+
+    /// Returns the first edge of the graph, if any.
+    pub fn first(&self) -> Option<VertexNode> {
+        self.buckets.iter().flatten().next().copied()
+    }
+
+    /// Consumes the graph, walking its edges into loops.
+    pub fn into_loops(mut self) -> Vec<GeoLoop> {
+        let mut loops = Vec::new();
+
+        // find the next unused entry point
+        while let Some(mut edge) = self.first() {
+            let mut verts = Vec::new();
+
+            // walk the graph to get the outline
+            loop {
+                verts.push(edge.from);
+                self.remove(&edge);
+
+                match self.find_vertex(&edge.to) {
+                    Some(next) => edge = next,
+                    None => break,
+                }
+            }
+
+            loops.push(GeoLoop::new(verts));
+        }
+
+        loops
+    }
+}
+
+/// Quantizes a vertex to steps of [`EPSILON_RAD`]. Almost equal vertices are
+/// in the same or adjacent steps.
+fn quantize(vertex: &LatLng) -> (i64, i64) {
+    (
+        (vertex.lat / EPSILON_RAD).floor() as i64,
+        (vertex.lng / EPSILON_RAD).floor() as i64,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::direction::Direction;
+
+    #[test]
+    fn add_find_remove() {
+        let a = LatLng::new(0.1, 0.2);
+        let b = LatLng::new(0.3, 0.4);
+        let c = LatLng::new(0.5, 0.6);
+
+        let mut graph = VertexGraph::new(10);
+        assert!(graph.is_empty());
+        assert_eq!(None, graph.first());
+
+        graph.add(&a, &b);
+        graph.add(&b, &c);
+        graph.add(&a, &b);
+        assert_eq!(2, graph.len());
+
+        let nudged = LatLng::new(a.lat + 1e-15, a.lng);
+        let edge = graph.find_edge(&nudged, &b).unwrap();
+        assert_eq!(VertexNode::new(a, b), edge);
+        assert_eq!(None, graph.find_edge(&b, &a));
+        assert_eq!(Some(VertexNode::new(b, c)), graph.find_vertex(&b));
+        assert_eq!(None, graph.find_vertex(&c));
+
+        // almost equal vertices on either side of a quantization step
+        let step = LatLng::new(100. * EPSILON_RAD, 0.2);
+        let below = LatLng::new(step.lat - EPSILON_RAD / 4., step.lng);
+        let above = LatLng::new(step.lat + EPSILON_RAD / 4., step.lng);
+        assert_ne!(quantize(&below), quantize(&above));
+        graph.add(&below, &c);
+        assert_eq!(Some(VertexNode::new(below, c)), graph.find_vertex(&above));
+        assert_eq!(Some(VertexNode::new(below, c)), graph.find_edge(&above, &c));
+        graph.remove(&VertexNode::new(below, c));
+        assert_eq!(2, graph.len());
+
+        graph.remove(&edge);
+        assert_eq!(1, graph.len());
+        assert_eq!(None, graph.find_edge(&a, &b));
+        assert_eq!(Some(VertexNode::new(b, c)), graph.first());
+    }
+
+    #[test]
+    fn from_cells() {
+        assert!(VertexGraph::from_cells(&[]).unwrap().is_empty());
+
+        let cell = H3Index::init(5, 20, Direction::JAxes);
+        let graph = VertexGraph::from_cells(core::slice::from_ref(&cell)).unwrap();
+        assert_eq!(6, graph.len());
+
+        // shared edges cancel out, leaving the 18 edges of the outer ring
+        let disk = cell.grid_disk(1).unwrap();
+        let graph = VertexGraph::from_cells(&disk).unwrap();
+        assert_eq!(18, graph.len());
+
+        let loops = graph.into_loops();
+        assert_eq!(1, loops.len());
+        assert_eq!(18, loops[0].verts.len());
+
+        assert_eq!(
+            Err(H3ErrorCode::ResMismatch),
+            VertexGraph::from_cells(&[cell.clone(), cell.parent(4).unwrap()]).map(|g| g.len())
+        );
+    }
+}
@@ -0,0 +1,57 @@
+mod common;
+
+/// Maximum difference allowed between a computed vertex and a fixture, in degrees.
+const TOLERANCE_DEGS: f64 = 0.000001;
+
+fn assert_cell_boundaries(name: &str) {
+    for (h3, expected) in common::cell_boundaries(name) {
+        let boundary = h3.boundary().unwrap();
+        assert_eq!(expected.len(), boundary.num_verts, "{}: {}", name, h3);
+
+        for (vert, expected) in boundary.verts().iter().zip(expected.iter()) {
+            let lng_diff = (vert.lng_degrees() - expected.lng_degrees()).rem_euclid(360.);
+
+            assert!(
+                (vert.lat_degrees() - expected.lat_degrees()).abs() < TOLERANCE_DEGS
+                    && lng_diff.min(360. - lng_diff) < TOLERANCE_DEGS,
+                "{}: vertex of {} is ({}, {}), expected ({}, {})",
+                name,
+                h3,
+                vert.lat_degrees(),
+                vert.lng_degrees(),
+                expected.lat_degrees(),
+                expected.lng_degrees(),
+            );
+        }
+    }
+}
+
+#[test]
+fn cell_to_boundary_coarse_resolutions() {
+    for name in common::fixtures_ending_with("cells.txt")
+        .iter()
+        .filter(|name| name.starts_with("res"))
+    {
+        assert_cell_boundaries(name);
+    }
+}
+
+#[test]
+fn cell_to_boundary_random_cells() {
+    for name in common::fixtures_ending_with("cells.txt")
+        .iter()
+        .filter(|name| name.starts_with("rand"))
+    {
+        assert_cell_boundaries(name);
+    }
+}
+
+#[test]
+fn cell_to_boundary_base_cell_cells() {
+    for name in common::fixtures_ending_with("cells.txt")
+        .iter()
+        .filter(|name| name.starts_with("bc"))
+    {
+        assert_cell_boundaries(name);
+    }
+}
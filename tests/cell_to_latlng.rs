@@ -0,0 +1,61 @@
+mod common;
+
+/// Maximum difference allowed between a computed center and a fixture, in degrees.
+const TOLERANCE_DEGS: f64 = 0.000001;
+
+fn assert_index_centers(name: &str) {
+    for (h3, expected) in common::cell_centers(name) {
+        let center = h3.to_latlng().unwrap();
+        let lng_diff = (center.lng_degrees() - expected.lng_degrees()).rem_euclid(360.);
+
+        assert!(
+            (center.lat_degrees() - expected.lat_degrees()).abs() < TOLERANCE_DEGS
+                && lng_diff.min(360. - lng_diff) < TOLERANCE_DEGS,
+            "{}: center of {} is ({}, {}), expected ({}, {})",
+            name,
+            h3,
+            center.lat_degrees(),
+            center.lng_degrees(),
+            expected.lat_degrees(),
+            expected.lng_degrees(),
+        );
+    }
+}
+
+#[test]
+fn cell_to_latlng_coarse_resolutions() {
+    for name in common::fixtures_ending_with("ic.txt") {
+        assert_index_centers(&name);
+    }
+}
+
+#[test]
+fn cell_to_latlng_round_trip() {
+    // the random fixtures hold arbitrary points within each cell rather than
+    // cell centers, so only check that the center lies within the cell
+    for name in common::fixtures_ending_with("centers.txt")
+        .iter()
+        .filter(|name| name.starts_with("rand"))
+    {
+        for (h3, _) in common::cell_centers(name) {
+            let center = h3.to_latlng().unwrap();
+            assert_eq!(
+                h3,
+                center.to_cell(h3.resolution()).unwrap(),
+                "{}: {}",
+                name,
+                h3
+            );
+        }
+    }
+}
+
+#[test]
+fn cell_to_latlng_base_cell_centers() {
+    for name in common::fixtures_ending_with("centers.txt")
+        .iter()
+        .filter(|name| name.starts_with("bc"))
+    {
+        assert_index_centers(name);
+    }
+}
@@ -0,0 +1,164 @@
+mod common;
+
+use h3_rs::{
+    direction::Direction,
+    latlng::LatLng,
+    polygon::{cells_to_multi_polygon, polygon_to_cells, GeoLoop, GeoPolygon},
+    result::H3ErrorCode,
+    H3Index,
+};
+
+fn assert_same_verts(expected: &[LatLng], actual: &[LatLng]) {
+    assert_eq!(expected.len(), actual.len());
+    for vert in expected {
+        assert!(actual.iter().any(|v| v.almost_equals(vert)), "{:?}", vert);
+    }
+}
+
+#[test]
+fn empty() {
+    assert_eq!(Ok(vec![]), cells_to_multi_polygon(&[]));
+}
+
+#[test]
+fn single_cell() {
+    let h3 = common::cell("890dab6220bffff");
+    let polygons = cells_to_multi_polygon(std::slice::from_ref(&h3)).unwrap();
+    assert_eq!(1, polygons.len());
+    assert!(polygons[0].holes.is_empty());
+    assert!(!polygons[0].geoloop.is_clockwise());
+    assert_same_verts(h3.boundary().unwrap().verts(), &polygons[0].geoloop.verts);
+}
+
+#[test]
+fn pentagon() {
+    let h3 = H3Index::init(3, 14, Direction::Center);
+    let polygons = cells_to_multi_polygon(std::slice::from_ref(&h3)).unwrap();
+    assert_eq!(1, polygons.len());
+    assert_same_verts(h3.boundary().unwrap().verts(), &polygons[0].geoloop.verts);
+}
+
+#[test]
+fn contiguous_disks() {
+    let origin = common::cell("8928308280fffff");
+    for k in 1..=3 {
+        let disk = origin.grid_disk(k).unwrap();
+        let polygons = cells_to_multi_polygon(&disk).unwrap();
+        assert_eq!(1, polygons.len());
+        assert!(polygons[0].holes.is_empty());
+        // each cell on the outer ring contributes two or three edges
+        assert_eq!(6 * (2 * k + 1), polygons[0].geoloop.verts.len());
+    }
+}
+
+#[test]
+fn disks_across_icosahedron_edges() {
+    // class III cells crossing icosahedron edges have distortion vertices
+    // computed separately on each face, which must still be matched up
+    for res in [1, 3, 5] {
+        for origin in common::all_cells(0)
+            .iter()
+            .map(|bc| bc.center_child(res).unwrap())
+        {
+            let disk = origin.grid_disk(2).unwrap();
+            let polygons = cells_to_multi_polygon(&disk).unwrap();
+            assert_eq!(1, polygons.len(), "disk around {}", origin);
+            assert!(polygons[0].holes.is_empty(), "disk around {}", origin);
+
+            // the outline runs entirely along the outer ring of the disk
+            let ring_verts: Vec<_> = origin
+                .grid_ring(2)
+                .unwrap()
+                .iter()
+                .flat_map(|cell| cell.boundary().unwrap().verts().to_vec())
+                .collect();
+            for vert in &polygons[0].geoloop.verts {
+                assert!(
+                    ring_verts.iter().any(|v| v.almost_equals(vert)),
+                    "{:?} in disk around {}",
+                    vert,
+                    origin
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn ring_with_hole() {
+    let origin = common::cell("8928308280fffff");
+    let ring = origin.grid_ring(1).unwrap();
+    let polygons = cells_to_multi_polygon(&ring).unwrap();
+
+    assert_eq!(1, polygons.len());
+    assert_eq!(18, polygons[0].geoloop.verts.len());
+    assert_eq!(1, polygons[0].holes.len());
+    assert!(polygons[0].holes[0].is_clockwise());
+    assert_same_verts(
+        origin.boundary().unwrap().verts(),
+        &polygons[0].holes[0].verts,
+    );
+}
+
+#[test]
+fn nested_donuts() {
+    let origin = common::cell("8928308280fffff");
+    let mut cells = origin.grid_ring(2).unwrap();
+    cells.extend(origin.grid_ring(4).unwrap());
+    cells.push(origin.clone());
+
+    let polygons = cells_to_multi_polygon(&cells).unwrap();
+    assert_eq!(3, polygons.len());
+
+    // the hole of the inner donut is also within the outer donut, but belongs
+    // to the inner one
+    let mut sizes: Vec<_> = polygons
+        .iter()
+        .map(|p| {
+            (
+                p.geoloop.verts.len(),
+                p.holes.iter().map(|h| h.verts.len()).collect(),
+            )
+        })
+        .collect();
+    sizes.sort();
+    assert_eq!(vec![(6, vec![]), (30, vec![18]), (54, vec![42])], sizes);
+}
+
+#[test]
+fn disjoint_cells() {
+    let a = common::cell("8928308280fffff");
+    let b = a.grid_ring(2).unwrap()[0].clone();
+    let polygons = cells_to_multi_polygon(&[a, b]).unwrap();
+    assert_eq!(2, polygons.len());
+    assert!(polygons.iter().all(|p| p.holes.is_empty()));
+}
+
+#[test]
+fn polygon_to_cells_round_trip() {
+    let square = GeoPolygon::new(
+        GeoLoop::new(vec![
+            LatLng::new(0.6595, -2.1360),
+            LatLng::new(0.6595, -2.1380),
+            LatLng::new(0.6585, -2.1380),
+            LatLng::new(0.6585, -2.1360),
+        ]),
+        vec![],
+    );
+    let cells = polygon_to_cells(&square, 9).unwrap();
+    let polygons = cells_to_multi_polygon(&cells).unwrap();
+    assert_eq!(1, polygons.len());
+
+    // the outline covers exactly the original cells
+    let refilled = polygon_to_cells(&polygons[0], 9).unwrap();
+    assert!(refilled.iter().all(|c| cells.contains(c)));
+}
+
+#[test]
+fn mixed_resolutions() {
+    let h3 = common::cell("8928308280fffff");
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        cells_to_multi_polygon(&[h3.clone(), h3.parent(8).unwrap()])
+    );
+}
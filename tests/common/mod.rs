@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use h3_rs::{latlng::LatLng, H3Index};
+
+/// Reads the contents of the fixture `name` from `tests/data`.
+pub fn fixture(name: &str) -> String {
+    let path = format!("{}/tests/data/{}", env!("CARGO_MANIFEST_DIR"), name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("reading {}: {}", path, e))
+}
+
+/// Returns the names of all fixtures in `tests/data` ending in `suffix`.
+pub fn fixtures_ending_with(suffix: &str) -> Vec<String> {
+    let dir = format!("{}/tests/data", env!("CARGO_MANIFEST_DIR"));
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .filter(|name| name.ends_with(suffix))
+        .collect();
+
+    names.sort();
+    names
+}
+
+/// Parses a fixture of `<index> <lat> <lng>` lines, with coordinates in degrees.
+pub fn cell_centers(name: &str) -> Vec<(H3Index, LatLng)> {
+    fixture(name)
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let h3 = H3Index::try_from(fields[0]).unwrap();
+            let lat: f64 = fields[1].parse().unwrap();
+            let lng: f64 = fields[2].parse().unwrap();
+            (h3, LatLng::from_degrees(lat, lng))
+        })
+        .collect()
+}
+
+/// Parses a fixture of `<index> { <lat> <lng> ... }` blocks, with coordinates
+/// in degrees.
+pub fn cell_boundaries(name: &str) -> Vec<(H3Index, Vec<LatLng>)> {
+    let mut boundaries = Vec::new();
+    let contents = fixture(name);
+    let mut lines = contents.lines().map(str::trim);
+
+    while let Some(line) = lines.next() {
+        if line.is_empty() {
+            continue;
+        }
+
+        let h3 = H3Index::try_from(line).unwrap();
+        assert_eq!(Some("{"), lines.next());
+
+        let verts = lines
+            .by_ref()
+            .take_while(|line| *line != "}")
+            .map(|line| {
+                let fields: Vec<f64> = line
+                    .split_whitespace()
+                    .map(|field| field.parse().unwrap())
+                    .collect();
+                LatLng::from_degrees(fields[0], fields[1])
+            })
+            .collect();
+
+        boundaries.push((h3, verts));
+    }
+
+    boundaries
+}
+
+/// Parses a cell from its hex string form.
+pub fn cell(s: &str) -> H3Index {
+    H3Index::try_from(s).unwrap()
+}
+
+/// Whether `vert` is almost equal to any of `verts`.
+pub fn contains_vert(verts: &[LatLng], vert: &LatLng) -> bool {
+    verts.iter().any(|v| v.almost_equals(vert))
+}
+
+/// Enumerates every valid cell at resolution `res`.
+pub fn all_cells(res: usize) -> Vec<H3Index> {
+    let mut cells: Vec<u64> = (0..122u64)
+        .map(|bc| {
+            // cell mode, resolution 0, unused digits set to 7
+            (1 << 59) | (bc << 45) | ((1 << 45) - 1)
+        })
+        .collect();
+
+    for r in 1..=res as u64 {
+        let offset = (15 - r) * 3;
+        cells = cells
+            .into_iter()
+            .flat_map(|h| {
+                let h = (h & !(15 << 52)) | (r << 52);
+                (0..7).map(move |digit| (h & !(7 << offset)) | (digit << offset))
+            })
+            .collect();
+    }
+
+    cells
+        .into_iter()
+        .map(H3Index::new)
+        .filter(H3Index::valid_cell)
+        .collect()
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{
+    direction::Direction,
+    index::{compact_cells, uncompact_cells, uncompact_cells_size},
+    result::H3ErrorCode,
+    H3Index,
+};
+
+fn as_set(cells: &[H3Index]) -> HashSet<H3Index> {
+    cells.iter().cloned().collect()
+}
+
+#[test]
+fn compact_disk_round_trip() {
+    let origin = common::cell("89283470c27ffff");
+    let disk = origin.grid_disk(9).unwrap();
+
+    let compacted = compact_cells(&disk).unwrap();
+    assert!(compacted.len() < disk.len());
+    assert!(compacted.iter().any(|c| c.resolution() < 9));
+
+    let uncompacted: Vec<_> = uncompact_cells(&compacted, 9).unwrap().collect();
+    assert_eq!(disk.len(), uncompacted.len());
+    assert_eq!(as_set(&disk), as_set(&uncompacted));
+}
+
+#[test]
+fn compact_all_children_to_base_cells() {
+    for base_cell in [0, 4, 14, 117] {
+        let parent = H3Index::init(0, base_cell, Direction::Center);
+        let children: Vec<_> = parent.children(3).unwrap().collect();
+        assert_eq!(Ok(vec![parent.clone()]), compact_cells(&children));
+    }
+
+    let res_0: Vec<_> = common::all_cells(0);
+    assert_eq!(Ok(res_0.clone()), compact_cells(&res_0));
+}
+
+#[test]
+fn compact_incomplete_siblings() {
+    let parent = common::cell("85283473fffffff");
+    let mut children: Vec<_> = parent.children(7).unwrap().collect();
+    children.pop();
+
+    let compacted = compact_cells(&children).unwrap();
+    assert_eq!(6 + 6, compacted.len());
+    assert_eq!(
+        as_set(&children),
+        as_set(&uncompact_cells(&compacted, 7).unwrap().collect::<Vec<_>>())
+    );
+}
+
+#[test]
+fn compact_errors() {
+    assert_eq!(Ok(vec![]), compact_cells(&[]));
+
+    let h3 = common::cell("8928308280fffff");
+    assert_eq!(
+        Err(H3ErrorCode::DuplicateInput),
+        compact_cells(&[h3.clone(), h3.clone()])
+    );
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        compact_cells(&[h3.clone(), h3.parent(8).unwrap()])
+    );
+}
+
+#[test]
+fn uncompact_sizes_and_errors() {
+    let hexagon = common::cell("8928308280fffff");
+    let pentagon = H3Index::init(9, 4, Direction::Center);
+    let cells = [hexagon.clone(), pentagon.clone()];
+
+    assert_eq!(Ok(49 + 1 + 5 * 8), uncompact_cells_size(&cells, 11));
+    assert_eq!(90, uncompact_cells(&cells, 11).unwrap().count());
+    assert_eq!(
+        vec![hexagon.clone()],
+        uncompact_cells(std::slice::from_ref(&hexagon), 9)
+            .unwrap()
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(0, uncompact_cells(&[], 5).unwrap().count());
+
+    assert_eq!(
+        Some(H3ErrorCode::ResMismatch),
+        uncompact_cells(&cells, 8).err()
+    );
+    assert_eq!(
+        Err(H3ErrorCode::ResDomain),
+        uncompact_cells_size(&cells, 16)
+    );
+}
+
+#[test]
+fn uncompact_base_cell_to_finest_res() {
+    let base_cell = H3Index::init(0, 0, Direction::Center);
+    let cells = std::slice::from_ref(&base_cell);
+
+    assert_eq!(Ok(4_747_561_509_943), uncompact_cells_size(cells, 15));
+
+    // far too many children to buffer, but they can still be walked
+    let first: Vec<_> = uncompact_cells(cells, 15).unwrap().take(7).collect();
+    assert_eq!(7, first.len());
+    assert!(first
+        .iter()
+        .all(|c| c.resolution() == 15 && c.parent(0) == Ok(base_cell.clone())));
+}
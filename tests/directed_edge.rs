@@ -0,0 +1,176 @@
+mod common;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index};
+
+#[test]
+fn known_edge() {
+    let origin = common::cell("85283473fffffff");
+    let edges = origin.origin_to_directed_edges().unwrap();
+    assert_eq!(6, edges.len());
+    assert_eq!(common::cell("115283473fffffff"), edges[0]);
+
+    let destination = edges[0].destination().unwrap();
+    assert_eq!(
+        Ok(edges[0].clone()),
+        origin.cells_to_directed_edge(&destination)
+    );
+    assert_eq!(
+        Ok((origin.clone(), destination)),
+        edges[0].directed_edge_to_cells()
+    );
+}
+
+#[test]
+fn edges_of_all_cells() {
+    for res in 0..=2 {
+        for origin in common::all_cells(res) {
+            let edges = origin.origin_to_directed_edges().unwrap();
+            assert_eq!(if origin.is_pentagon() { 5 } else { 6 }, edges.len());
+
+            let origin_verts = origin.boundary().unwrap();
+            for edge in edges {
+                assert!(edge.is_valid_directed_edge(), "{}", edge);
+                assert!(!edge.valid_cell());
+                assert_eq!(Ok(origin.clone()), edge.origin());
+
+                let destination = edge.destination().unwrap();
+                assert!(destination.valid_cell());
+                assert_eq!(
+                    Ok(edge.clone()),
+                    origin.cells_to_directed_edge(&destination)
+                );
+
+                // the reverse edge has the same topological vertices in reverse
+                // order; any distortion vertex between them is computed on a
+                // different face, in single precision
+                let reverse = destination.cells_to_directed_edge(&origin).unwrap();
+                let boundary = edge.directed_edge_to_boundary().unwrap();
+                let mut reverse_verts = reverse
+                    .directed_edge_to_boundary()
+                    .unwrap()
+                    .verts()
+                    .to_vec();
+                reverse_verts.reverse();
+
+                let verts = boundary.verts();
+                assert!((2..=3).contains(&verts.len()), "{}", edge);
+                assert_eq!(verts.len(), reverse_verts.len(), "{}", edge);
+                assert!(verts[0].almost_equals(&reverse_verts[0]), "{}", edge);
+                assert!(
+                    verts[verts.len() - 1].almost_equals(&reverse_verts[verts.len() - 1]),
+                    "{}",
+                    edge
+                );
+
+                // and both topological vertices are on both cells
+                let destination_verts = destination.boundary().unwrap();
+                for vert in [&verts[0], &verts[verts.len() - 1]] {
+                    assert!(
+                        common::contains_vert(origin_verts.verts(), vert),
+                        "{}",
+                        edge
+                    );
+                    assert!(
+                        common::contains_vert(destination_verts.verts(), vert),
+                        "{}",
+                        edge
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn not_neighbors() {
+    let origin = common::cell("8928308280fffff");
+    assert_eq!(
+        Err(H3ErrorCode::NotNeighbors),
+        origin.cells_to_directed_edge(&origin)
+    );
+
+    let far = &origin.grid_ring(2).unwrap()[0];
+    assert_eq!(
+        Err(H3ErrorCode::NotNeighbors),
+        origin.cells_to_directed_edge(far)
+    );
+}
+
+#[test]
+fn cells_to_directed_edge_validates_cells() {
+    let origin = common::cell("8928308280fffff");
+    let neighbor = &origin.grid_ring(1).unwrap()[0];
+
+    let child = origin.center_child(10).unwrap();
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        origin.cells_to_directed_edge(&child)
+    );
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        child.cells_to_directed_edge(neighbor)
+    );
+
+    let edge = origin.cells_to_directed_edge(neighbor).unwrap();
+    for invalid in [&edge, &H3Index::new(0)] {
+        assert_eq!(
+            Err(H3ErrorCode::CellInvalid),
+            origin.cells_to_directed_edge(invalid)
+        );
+        assert_eq!(
+            Err(H3ErrorCode::CellInvalid),
+            invalid.cells_to_directed_edge(neighbor)
+        );
+    }
+}
+
+#[test]
+fn invalid_edges() {
+    let origin = common::cell("8928308280fffff");
+    assert!(!origin.is_valid_directed_edge());
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), origin.origin());
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), origin.destination());
+    assert_eq!(
+        Err(H3ErrorCode::DirEdgeInvalid),
+        origin.directed_edge_to_boundary()
+    );
+
+    // no edge in the center or an invalid direction
+    let edge = origin.origin_to_directed_edges().unwrap()[0].clone();
+    let center = H3Index::new(&edge & !(0b111 << 56));
+    assert!(!center.is_valid_directed_edge());
+    assert_eq!(
+        Err(H3ErrorCode::DirEdgeInvalid),
+        center.directed_edge_to_boundary()
+    );
+    let invalid = H3Index::new((&edge & u64::MAX) | (0b111 << 56));
+    assert!(!invalid.is_valid_directed_edge());
+    // no edge from an origin that is not a valid cell
+    let bad_origin = H3Index::new((&edge & !(0x7f << 45)) | (127 << 45));
+    assert!(!bad_origin.is_valid_directed_edge());
+    for edge in [&center, &invalid, &bad_origin] {
+        assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), edge.origin());
+        assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), edge.destination());
+        assert_eq!(
+            Err(H3ErrorCode::DirEdgeInvalid),
+            edge.directed_edge_to_cells()
+        );
+    }
+
+    // no edge in the deleted k direction of a pentagon
+    let pentagon = H3Index::init(2, 4, Direction::Center);
+    let edges = pentagon.origin_to_directed_edges().unwrap();
+    let k_edge = H3Index::new((&edges[0] & !(0b111 << 56)) | (1 << 56));
+    assert!(edges.iter().all(|e| e != &k_edge));
+    assert!(!k_edge.is_valid_directed_edge());
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), k_edge.origin());
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), k_edge.destination());
+    assert_eq!(
+        Err(H3ErrorCode::DirEdgeInvalid),
+        k_edge.directed_edge_to_cells()
+    );
+    assert_eq!(
+        Err(H3ErrorCode::DirEdgeInvalid),
+        k_edge.directed_edge_to_boundary()
+    );
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{direction::Direction, index::max_grid_disk_size, result::H3ErrorCode, H3Index};
+
+#[test]
+fn grid_disk_known_neighbors() {
+    let origin = common::cell("8928308280fffff");
+    let expected: HashSet<H3Index> = [
+        "8928308280fffff",
+        "8928308280bffff",
+        "89283082807ffff",
+        "89283082877ffff",
+        "89283082803ffff",
+        "89283082873ffff",
+        "8928308283bffff",
+    ]
+    .into_iter()
+    .map(common::cell)
+    .collect();
+
+    let disk = origin.grid_disk(1).unwrap();
+    assert_eq!(7, disk.len());
+    assert_eq!(expected, disk.into_iter().collect());
+    assert_eq!(vec![origin.clone()], origin.grid_disk(0).unwrap());
+}
+
+#[test]
+fn grid_disk_polar_pentagon() {
+    let pentagon = H3Index::init(0, 4, Direction::Center);
+    let expected: HashSet<H3Index> = [4, 15, 8, 3, 0, 12]
+        .into_iter()
+        .map(|bc| H3Index::init(0, bc, Direction::Center))
+        .collect();
+
+    let disk = pentagon.grid_disk(1).unwrap();
+    assert_eq!(6, disk.len());
+    assert_eq!(expected, disk.into_iter().collect());
+
+    assert_eq!(Err(H3ErrorCode::Pentagon), pentagon.grid_disk_unsafe(1));
+}
+
+#[test]
+fn grid_disk_unsafe_matches_safe() {
+    let origin = common::cell("8928308280fffff");
+    let unsafe_disk = origin.grid_disk_distances_unsafe(6).unwrap();
+    assert_eq!(max_grid_disk_size(6), unsafe_disk.len());
+
+    // the spiral produces cells in order of increasing distance
+    assert!(unsafe_disk.windows(2).all(|w| w[0].1 <= w[1].1));
+
+    let safe: HashSet<(H3Index, usize)> =
+        origin.grid_disk_distances(6).unwrap().into_iter().collect();
+    assert_eq!(safe, unsafe_disk.into_iter().collect());
+}
+
+#[test]
+fn grid_disk_distances_all_cells() {
+    for res in 0..=2 {
+        for origin in common::all_cells(res) {
+            let disk = origin.grid_disk_distances(2).unwrap();
+            let cells: HashSet<H3Index> = disk.iter().map(|(h3, _)| h3.clone()).collect();
+            assert_eq!(disk.len(), cells.len(), "duplicates around {}", origin);
+            assert!(disk.len() <= max_grid_disk_size(2));
+
+            let ring_1 = disk.iter().filter(|(_, d)| *d == 1).count();
+            if origin.is_pentagon() {
+                assert_eq!(5, ring_1, "{}", origin);
+                assert_eq!(
+                    Err(H3ErrorCode::Pentagon),
+                    origin.grid_disk_distances_unsafe(2)
+                );
+            } else {
+                assert_eq!(6, ring_1, "{}", origin);
+            }
+
+            // distances are symmetric
+            for (h3, distance) in &disk {
+                assert!(h3.valid_cell());
+                assert!(h3
+                    .grid_disk_distances(*distance)
+                    .unwrap()
+                    .contains(&(origin.clone(), *distance)));
+            }
+        }
+    }
+}
+
+#[test]
+fn grid_disk_huge_k() {
+    assert_eq!(569_707_381_193_162, max_grid_disk_size(usize::MAX));
+    assert_eq!(569_707_381_193_162, max_grid_disk_size(13_780_510));
+    assert_eq!(569_707_326_238_771, max_grid_disk_size(13_780_509));
+
+    // the disk stops growing once it covers the globe
+    let origin = H3Index::init(0, 0, Direction::Center);
+    let disk = origin.grid_disk(1 << 40).unwrap();
+    assert_eq!(122, disk.len());
+    assert!(origin.grid_ring(1 << 40).unwrap().is_empty());
+}
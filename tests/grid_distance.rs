@@ -0,0 +1,46 @@
+mod common;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index};
+
+#[test]
+fn grid_distance_known_cells() {
+    let origin = H3Index::try_from("8928308280fffff").unwrap();
+    assert_eq!(Ok(0), origin.grid_distance(&origin));
+
+    for (cell, distance) in origin.grid_disk_distances(5).unwrap() {
+        assert_eq!(Ok(distance as i64), origin.grid_distance(&cell));
+        assert_eq!(Ok(distance as i64), cell.grid_distance(&origin));
+    }
+}
+
+#[test]
+fn grid_distance_errors() {
+    let origin = H3Index::try_from("8928308280fffff").unwrap();
+    let parent = H3Index::init(0, origin.base_cell() as u64, Direction::Center);
+    assert_eq!(Err(H3ErrorCode::ResMismatch), origin.grid_distance(&parent));
+
+    let a = H3Index::init(0, 0, Direction::Center);
+    let b = H3Index::init(0, 121, Direction::Center);
+    assert_eq!(Err(H3ErrorCode::Failed), a.grid_distance(&b));
+}
+
+#[test]
+fn grid_distance_matches_grid_disk() {
+    for res in 0..=2 {
+        for origin in common::all_cells(res) {
+            for (cell, distance) in origin.grid_disk_distances(3).unwrap() {
+                match origin.grid_distance(&cell) {
+                    Ok(d) => assert_eq!(distance as i64, d, "{} -> {}", origin, cell),
+                    // pentagon distortion may prevent a local frame
+                    Err(e) => assert!(
+                        e == H3ErrorCode::Pentagon || e == H3ErrorCode::Failed,
+                        "{} -> {}: {:?}",
+                        origin,
+                        cell,
+                        e
+                    ),
+                }
+            }
+        }
+    }
+}
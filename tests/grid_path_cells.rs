@@ -0,0 +1,54 @@
+mod common;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index};
+
+/// Asserts that `path` runs from `start` to `end` through neighboring cells.
+fn assert_contiguous(start: &H3Index, end: &H3Index, path: &[H3Index]) {
+    assert_eq!(Some(start), path.first());
+    assert_eq!(Some(end), path.last());
+    assert_eq!(start.grid_path_cells_size(end).unwrap(), path.len() as i64);
+
+    for pair in path.windows(2) {
+        assert_eq!(Ok(1), pair[0].grid_distance(&pair[1]), "{:?}", path);
+    }
+}
+
+#[test]
+fn grid_path_cells_known_line() {
+    let start = H3Index::try_from("8928308280fffff").unwrap();
+    assert_eq!(vec![start.clone()], start.grid_path_cells(&start).unwrap());
+
+    for (end, distance) in start.grid_disk_distances(6).unwrap() {
+        let path = start.grid_path_cells(&end).unwrap();
+        assert_eq!(distance + 1, path.len());
+        assert_contiguous(&start, &end, &path);
+    }
+}
+
+#[test]
+fn grid_path_cells_errors() {
+    let start = H3Index::try_from("8928308280fffff").unwrap();
+    let parent = H3Index::init(0, start.base_cell() as u64, Direction::Center);
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        start.grid_path_cells(&parent)
+    );
+
+    let a = H3Index::init(0, 0, Direction::Center);
+    let b = H3Index::init(0, 121, Direction::Center);
+    assert_eq!(Err(H3ErrorCode::Failed), a.grid_path_cells(&b));
+}
+
+#[test]
+fn grid_path_cells_all_cells() {
+    for res in 0..=2 {
+        for start in common::all_cells(res) {
+            for end in start.grid_ring(2).unwrap() {
+                // pentagon distortion may prevent drawing a line
+                if let Ok(path) = start.grid_path_cells(&end) {
+                    assert_contiguous(&start, &end, &path);
+                }
+            }
+        }
+    }
+}
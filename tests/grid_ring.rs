@@ -0,0 +1,67 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index, NUM_BASE_CELLS};
+
+/// Asserts that ring `k` around `origin` is disk `k` minus disk `k - 1`.
+fn assert_ring_is_disk_difference(origin: &H3Index, k: usize) {
+    let ring = origin.grid_ring(k).unwrap();
+    let ring_set: HashSet<H3Index> = ring.iter().cloned().collect();
+    assert_eq!(ring.len(), ring_set.len(), "duplicates around {}", origin);
+
+    let inner: HashSet<H3Index> = origin.grid_disk(k - 1).unwrap().into_iter().collect();
+    let outer: HashSet<H3Index> = origin.grid_disk(k).unwrap().into_iter().collect();
+    assert_eq!(
+        outer.difference(&inner).cloned().collect::<HashSet<_>>(),
+        ring_set,
+        "ring {} around {}",
+        k,
+        origin
+    );
+
+    // the fast variant either agrees or reports the pentagon
+    match origin.grid_ring_unsafe(k) {
+        Ok(fast) => assert_eq!(ring_set, fast.into_iter().collect()),
+        Err(e) => assert_eq!(H3ErrorCode::Pentagon, e),
+    }
+}
+
+#[test]
+fn grid_ring_known_cells() {
+    let origin = H3Index::try_from("8928308280fffff").unwrap();
+    assert_eq!(vec![origin.clone()], origin.grid_ring(0).unwrap());
+
+    let ring = origin.grid_ring_unsafe(1).unwrap();
+    assert_eq!(6, ring.len());
+    assert!(!ring.contains(&origin));
+
+    for k in 1..=5 {
+        assert_eq!(6 * k, origin.grid_ring_unsafe(k).unwrap().len());
+        assert_ring_is_disk_difference(&origin, k);
+    }
+}
+
+#[test]
+fn grid_ring_every_base_cell() {
+    for bc in 0..NUM_BASE_CELLS as u64 {
+        for res in 0..=2 {
+            let origin = H3Index::init(res, bc, Direction::Center);
+            for k in 1..=3 {
+                assert_ring_is_disk_difference(&origin, k);
+            }
+
+            if origin.is_pentagon() {
+                assert_eq!(Err(H3ErrorCode::Pentagon), origin.grid_ring_unsafe(1));
+                assert_eq!(5, origin.grid_ring(1).unwrap().len());
+            }
+        }
+    }
+}
+
+#[test]
+fn grid_ring_near_pentagons() {
+    for origin in common::all_cells(1) {
+        assert_ring_is_disk_difference(&origin, 2);
+    }
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index};
+
+#[test]
+fn parent_known_cells() {
+    let h3 = common::cell("8928308280fffff");
+    assert_eq!(Ok(h3.clone()), h3.parent(9));
+    assert_eq!(Ok(common::cell("8828308281fffff")), h3.parent(8));
+    assert_eq!(Ok(common::cell("85283083fffffff")), h3.parent(5));
+    assert_eq!(
+        Ok(H3Index::init(0, h3.base_cell() as u64, Direction::Center)),
+        h3.parent(0)
+    );
+
+    assert_eq!(Err(H3ErrorCode::ResMismatch), h3.parent(10));
+    assert_eq!(Err(H3ErrorCode::ResDomain), h3.parent(16));
+}
+
+#[test]
+fn center_child_shares_center() {
+    let h3 = common::cell("85283473fffffff");
+    let center = h3.to_latlng().unwrap();
+
+    for res in 5..=15 {
+        let child = h3.center_child(res).unwrap();
+        assert_eq!(res, child.resolution());
+        assert_eq!(Ok(h3.clone()), child.parent(5));
+
+        let child_center = child.to_latlng().unwrap();
+        assert!((center.lat - child_center.lat).abs() < 1e-9);
+        assert!((center.lng - child_center.lng).abs() < 1e-9);
+    }
+
+    assert_eq!(Err(H3ErrorCode::ResDomain), h3.center_child(4));
+    assert_eq!(Err(H3ErrorCode::ResDomain), h3.center_child(16));
+}
+
+#[test]
+fn children_of_every_res_0_cell() {
+    for parent in common::all_cells(0) {
+        for res in 0..=3 {
+            let children: Vec<H3Index> = parent.children(res).unwrap().collect();
+            let unique: HashSet<&H3Index> = children.iter().collect();
+
+            assert_eq!(parent.children_count(res).unwrap(), children.len() as i64);
+            assert_eq!(children.len(), unique.len());
+
+            // children are produced in order
+            assert!(children.windows(2).all(|w| w[0] < w[1]));
+
+            for child in &children {
+                assert!(child.valid_cell(), "{}", child);
+                assert_eq!(res, child.resolution());
+                assert_eq!(Ok(parent.clone()), child.parent(0));
+            }
+        }
+    }
+}
+
+#[test]
+fn children_counts() {
+    let hexagon = common::cell("8928308280fffff");
+    assert_eq!(Ok(1), hexagon.children_count(9));
+    assert_eq!(Ok(7), hexagon.children_count(10));
+    assert_eq!(Ok(49), hexagon.children_count(11));
+    assert_eq!(Err(H3ErrorCode::ResDomain), hexagon.children_count(8));
+    assert!(hexagon.children(16).is_err());
+
+    let pentagon = H3Index::init(1, 4, Direction::Center);
+    assert_eq!(Ok(6), pentagon.children_count(2));
+    assert_eq!(Ok(41), pentagon.children_count(3));
+    assert_eq!(6, pentagon.children(2).unwrap().count());
+    assert!(pentagon
+        .children(3)
+        .unwrap()
+        .all(|child| child.leading_non_zero_digit() != Direction::KAxes));
+
+    // the iterator is lazy
+    let first: Vec<H3Index> = hexagon.children(15).unwrap().take(2).collect();
+    assert_eq!(vec![hexagon.center_child(15).unwrap()], first[..1]);
+    assert_eq!(2, first.len());
+}
+
+#[test]
+fn siblings() {
+    let h3 = common::cell("8928308280fffff");
+    let siblings = h3.siblings().unwrap();
+    assert_eq!(6, siblings.len());
+    assert!(!siblings.contains(&h3));
+    assert!(siblings
+        .iter()
+        .all(|sibling| sibling.parent(8) == h3.parent(8)));
+
+    let pentagon_child = H3Index::init(2, 4, Direction::Center);
+    assert_eq!(5, pentagon_child.siblings().unwrap().len());
+
+    assert_eq!(
+        Err(H3ErrorCode::ResDomain),
+        H3Index::init(0, 0, Direction::Center).siblings()
+    );
+}
+
+#[test]
+fn child_pos_matches_children_order() {
+    let parents = [
+        common::cell("8928308280fffff"),
+        H3Index::init(1, 4, Direction::Center),
+        H3Index::init(0, 14, Direction::Center),
+        H3Index::init(0, 2, Direction::Center),
+    ];
+
+    for parent in parents {
+        let parent_res = parent.resolution();
+        for res in parent_res..=parent_res + 3 {
+            for (pos, child) in parent.children(res).unwrap().enumerate() {
+                assert_eq!(Ok(pos as i64), child.cell_to_child_pos(parent_res));
+                assert_eq!(Ok(child), parent.child_pos_to_cell(pos as i64, res));
+            }
+        }
+    }
+}
+
+#[test]
+fn child_pos_errors() {
+    let parent = common::cell("8928308280fffff");
+    let child = parent.center_child(11).unwrap();
+    assert_eq!(Err(H3ErrorCode::ResMismatch), child.cell_to_child_pos(12));
+    assert_eq!(Err(H3ErrorCode::ResDomain), child.cell_to_child_pos(16));
+
+    assert_eq!(Err(H3ErrorCode::Domain), parent.child_pos_to_cell(-1, 10));
+    assert_eq!(Err(H3ErrorCode::Domain), parent.child_pos_to_cell(7, 10));
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        parent.child_pos_to_cell(0, 8)
+    );
+    assert_eq!(Err(H3ErrorCode::ResDomain), parent.child_pos_to_cell(0, 16));
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use h3_rs::H3Index;
+
+fn assert_centers_index(name: &str) {
+    for (h3, center) in common::cell_centers(name) {
+        assert_eq!(
+            h3,
+            H3Index::from_latlng(&center, h3.resolution()).unwrap(),
+            "{}: center of {} ({}, {})",
+            name,
+            h3,
+            center.lat_degrees(),
+            center.lng_degrees(),
+        );
+    }
+}
+
+#[test]
+fn latlng_to_cell_random_centers() {
+    for name in common::fixtures_ending_with("centers.txt")
+        .iter()
+        .filter(|name| name.starts_with("rand"))
+    {
+        assert_centers_index(name);
+    }
+}
+
+#[test]
+fn latlng_to_cell_base_cell_centers() {
+    for name in common::fixtures_ending_with("centers.txt")
+        .iter()
+        .filter(|name| name.starts_with("bc"))
+    {
+        assert_centers_index(name);
+    }
+}
+
+#[test]
+fn latlng_to_cell_coarse_resolutions() {
+    for name in common::fixtures_ending_with("ic.txt") {
+        assert_centers_index(&name);
+    }
+}
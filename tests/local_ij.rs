@@ -0,0 +1,123 @@
+mod common;
+
+use h3_rs::{
+    base_cell::BaseCell,
+    coordinate::{CoordIJ, Coordinate},
+    direction::Direction,
+    result::H3ErrorCode,
+    H3Index,
+};
+
+#[test]
+fn local_ij_base_cell_neighbors() {
+    for bc in BaseCell::iter() {
+        if bc.is_pentagon() {
+            continue;
+        }
+
+        let origin = H3Index::init(0, bc.into(), Direction::Center);
+        assert_eq!(
+            CoordIJ::new(0, 0),
+            origin.cell_to_local_ij(&origin).unwrap()
+        );
+
+        for digit in 1..7usize {
+            let dir = Direction::from(digit);
+            let neighbor = bc.neighbor(dir).unwrap();
+            let cell = H3Index::init(0, neighbor.into(), Direction::Center);
+
+            let ij = origin.cell_to_local_ij(&cell).unwrap();
+            assert_eq!(
+                CoordIJ::from(&Coordinate::default().neighbor(dir)),
+                ij,
+                "base cell {:?} -> {:?}",
+                bc,
+                neighbor
+            );
+            assert_eq!(cell, origin.local_ij_to_cell(&ij).unwrap());
+        }
+    }
+}
+
+#[test]
+fn local_ij_errors() {
+    let origin = H3Index::try_from("8928308280fffff").unwrap();
+    let parent = H3Index::init(0, origin.base_cell() as u64, Direction::Center);
+    assert_eq!(
+        Err(H3ErrorCode::ResMismatch),
+        origin.cell_to_local_ij(&parent)
+    );
+
+    // base cells 0 and 121 are on opposite sides of the globe
+    let far = H3Index::init(0, 121, Direction::Center);
+    assert_eq!(
+        Err(H3ErrorCode::Failed),
+        H3Index::init(0, 0, Direction::Center).cell_to_local_ij(&far)
+    );
+
+    // too far from the origin to be in a neighboring base cell
+    assert_eq!(
+        Err(H3ErrorCode::Failed),
+        origin.local_ij_to_cell(&CoordIJ::new(1 << 30, 0))
+    );
+
+    // coordinates that would overflow when converted or re-expressed
+    for ij in [
+        CoordIJ::new(isize::MIN, 0),
+        CoordIJ::new(0, isize::MIN),
+        CoordIJ::new(isize::MAX, isize::MAX),
+        CoordIJ::new(isize::MAX / 2, isize::MIN / 2),
+        CoordIJ::new(1 << 40, 0),
+    ] {
+        assert_eq!(Err(H3ErrorCode::Failed), origin.local_ij_to_cell(&ij));
+    }
+
+    // moving into the deleted k subsequence of a pentagon
+    let pentagon = H3Index::init(0, 4, Direction::Center);
+    assert_eq!(
+        Err(H3ErrorCode::Failed),
+        pentagon.local_ij_to_cell(&CoordIJ::from(
+            &Coordinate::default().neighbor(Direction::KAxes)
+        ))
+    );
+}
+
+#[test]
+fn local_ij_round_trip() {
+    let mut pentagon_failures = 0;
+    for res in 0..=2 {
+        for origin in common::all_cells(res) {
+            for cell in origin.grid_disk(3).unwrap() {
+                // not every cell can be unfolded around a pentagon, but those
+                // that can must map back to the same cell
+                match origin.cell_to_local_ij(&cell) {
+                    Ok(ij) => assert_eq!(
+                        Ok(cell.clone()),
+                        origin.local_ij_to_cell(&ij),
+                        "{} around {} at {:?}",
+                        cell,
+                        origin,
+                        ij
+                    ),
+                    Err(H3ErrorCode::Pentagon) => pentagon_failures += 1,
+                    Err(e) => assert_eq!(H3ErrorCode::Failed, e),
+                }
+            }
+        }
+    }
+
+    assert!(pentagon_failures > 0);
+}
+
+#[test]
+fn local_ij_matches_grid_disk_distances() {
+    let origin = H3Index::try_from("8928308280fffff").unwrap();
+    let center = Coordinate::try_from(&origin.cell_to_local_ij(&origin).unwrap()).unwrap();
+
+    for (cell, distance) in origin.grid_disk_distances(4).unwrap() {
+        let ijk = Coordinate::try_from(&origin.cell_to_local_ij(&cell).unwrap()).unwrap();
+        let diff = (ijk - center).normalize();
+        let hops = diff.i.abs().max(diff.j.abs()).max(diff.k.abs());
+        assert_eq!(distance as isize, hops, "{}", cell);
+    }
+}
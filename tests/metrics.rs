@@ -0,0 +1,122 @@
+mod common;
+
+use h3_rs::{
+    latlng::{triangle_area_rads2, LatLng},
+    result::H3ErrorCode,
+    EARTH_RADIUS_KM, M_PI,
+};
+
+#[test]
+fn triangle_area() {
+    // one eighth of the sphere
+    let octant = triangle_area_rads2(
+        &LatLng::from_degrees(90., 0.),
+        &LatLng::from_degrees(0., 0.),
+        &LatLng::from_degrees(0., 90.),
+    );
+    assert!((octant - M_PI / 2.).abs() < 1e-12);
+
+    let point = LatLng::from_degrees(37., -122.);
+    assert_eq!(0., triangle_area_rads2(&point, &point, &point));
+}
+
+#[test]
+fn cell_areas_cover_the_earth() {
+    for res in 0..=2 {
+        let cells = common::all_cells(res);
+
+        let rads2: f64 = cells.iter().map(|c| c.cell_area_rads2().unwrap()).sum();
+        assert!((rads2 - 4. * M_PI).abs() < 1e-6, "res {}: {}", res, rads2);
+
+        let km2: f64 = cells.iter().map(|c| c.cell_area_km2().unwrap()).sum();
+        let earth_km2 = 4. * M_PI * EARTH_RADIUS_KM * EARTH_RADIUS_KM;
+        assert!((km2 - earth_km2).abs() / earth_km2 < 1e-9, "res {}", res);
+    }
+}
+
+#[test]
+fn cell_area_units() {
+    let h3 = common::cell("8928308280fffff");
+    let rads2 = h3.cell_area_rads2().unwrap();
+    let km2 = h3.cell_area_km2().unwrap();
+    let m2 = h3.cell_area_m2().unwrap();
+
+    assert!(rads2 > 0.);
+    assert!((km2 - rads2 * EARTH_RADIUS_KM * EARTH_RADIUS_KM).abs() < 1e-12);
+    assert!((m2 - km2 * 1e6).abs() < 1e-6);
+
+    // the average resolution 9 hexagon is about 0.105 km^2
+    assert!((0.08..0.13).contains(&km2), "{}", km2);
+
+    // children of a hexagon cover the same area
+    let children: f64 = h3
+        .children(11)
+        .unwrap()
+        .map(|c| c.cell_area_km2().unwrap())
+        .sum();
+    assert!((children - km2).abs() / km2 < 0.01, "{} {}", children, km2);
+}
+
+#[test]
+fn cell_areas_vary_by_location() {
+    let areas: Vec<f64> = common::all_cells(2)
+        .iter()
+        .filter(|c| !c.is_pentagon())
+        .map(|c| c.cell_area_km2().unwrap())
+        .collect();
+    let min = areas.iter().cloned().fold(f64::MAX, f64::min);
+    let max = areas.iter().cloned().fold(0., f64::max);
+
+    assert!(max / min > 1.5, "{} {}", min, max);
+    assert!(max / min < 2.5, "{} {}", min, max);
+}
+
+#[test]
+fn edge_lengths() {
+    for res in 0..=2 {
+        for origin in common::all_cells(res) {
+            let boundary = origin.boundary().unwrap();
+            let verts = boundary.verts();
+            let perimeter: f64 = verts
+                .iter()
+                .zip(verts.iter().cycle().skip(1))
+                .map(|(a, b)| a.great_circle_distance_rads(b))
+                .sum();
+
+            let mut edges = 0.;
+            for edge in origin.origin_to_directed_edges().unwrap() {
+                let rads = edge.edge_length_rads().unwrap();
+                assert!(rads > 0.);
+                assert!((edge.edge_length_km().unwrap() - rads * EARTH_RADIUS_KM).abs() < 1e-9);
+                assert!(
+                    (edge.edge_length_m().unwrap() - rads * EARTH_RADIUS_KM * 1000.).abs() < 1e-6
+                );
+
+                // both directions have the same length
+                let reverse = edge
+                    .destination()
+                    .unwrap()
+                    .cells_to_directed_edge(&origin)
+                    .unwrap();
+                assert!(
+                    (reverse.edge_length_rads().unwrap() - rads).abs() < 1e-6,
+                    "{}",
+                    edge
+                );
+
+                edges += rads;
+            }
+
+            // the edges make up the perimeter of the cell
+            assert!((edges - perimeter).abs() < 1e-9, "{}", origin);
+        }
+    }
+}
+
+#[test]
+fn edge_length_invalid() {
+    let h3 = common::cell("8928308280fffff");
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), h3.edge_length_rads());
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), h3.edge_length_km());
+    assert_eq!(Err(H3ErrorCode::DirEdgeInvalid), h3.edge_length_m());
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index};
+
+const DIRECTIONS: [Direction; 6] = [
+    Direction::KAxes,
+    Direction::JAxes,
+    Direction::JKAxes,
+    Direction::IAxes,
+    Direction::IKAxes,
+    Direction::IJAxes,
+];
+
+/// Returns the direction opposite `dir`.
+fn opposite(dir: Direction) -> Direction {
+    Direction::from(7 - usize::from(dir))
+}
+
+#[test]
+fn neighbor_matches_grid_disk() {
+    let origin = H3Index::try_from("8928308280fffff").unwrap();
+    assert_eq!(
+        (origin.clone(), 0),
+        origin.neighbor(Direction::Center).unwrap()
+    );
+
+    let neighbors: HashSet<H3Index> = DIRECTIONS
+        .into_iter()
+        .map(|dir| origin.neighbor(dir).unwrap().0)
+        .chain([origin.clone()])
+        .collect();
+    assert_eq!(
+        origin
+            .grid_disk(1)
+            .unwrap()
+            .into_iter()
+            .collect::<HashSet<_>>(),
+        neighbors
+    );
+
+    assert_eq!(
+        Err(H3ErrorCode::Failed),
+        origin.neighbor(Direction::Invalid)
+    );
+}
+
+#[test]
+fn neighbor_pentagon_deleted_subsequence() {
+    for pentagon in common::all_cells(2).iter().filter(|h3| h3.is_pentagon()) {
+        assert_eq!(
+            Err(H3ErrorCode::Pentagon),
+            pentagon.neighbor(Direction::KAxes)
+        );
+        assert_eq!(
+            Err(H3ErrorCode::Pentagon),
+            pentagon.neighbor(Direction::PentagonSkippedDigit)
+        );
+
+        let neighbors: HashSet<H3Index> = DIRECTIONS[1..]
+            .iter()
+            .map(|dir| pentagon.neighbor(*dir).unwrap().0)
+            .collect();
+        assert_eq!(5, neighbors.len(), "{}", pentagon);
+    }
+}
+
+#[test]
+fn neighbor_round_trip() {
+    for res in 0..=3 {
+        for origin in common::all_cells(res) {
+            for dir in DIRECTIONS {
+                let Ok((neighbor, rotations)) = origin.neighbor(dir) else {
+                    assert!(origin.is_pentagon());
+                    continue;
+                };
+                assert!(rotations < 6);
+                assert!(neighbor.valid_cell());
+                assert_ne!(origin, neighbor);
+
+                // stepping back, reoriented to the neighbor, returns to origin
+                // unless pentagonal distortion is involved
+                if !origin.is_pentagon() && !neighbor.is_pentagon() {
+                    let back = (0..rotations).fold(opposite(dir), |d, _| d.rotate_60_ccw());
+                    let (returned, _) = neighbor.neighbor(back).unwrap();
+                    assert_eq!(origin, returned, "{} -> {} ({:?})", origin, neighbor, dir);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use h3_rs::{
+    coordinate::Coordinate,
+    direction::Direction,
+    face::{Face, FaceIJK, Overage, Pentagon, KI, MAX_DIM_BY_CII_RES},
+    H3Index, NUM_BASE_CELLS,
+};
+
+#[test]
+fn adjust_overage_class_ii_onto_neighbor_face() {
+    let res = 2;
+    let max_dim = MAX_DIM_BY_CII_RES[res];
+
+    let mut inside = Face::new(3, Coordinate::new(max_dim, 0, 0));
+    assert_eq!(
+        Overage::NoOverage,
+        inside.adjust_overage_class_ii(res, false, false)
+    );
+
+    let mut edge = Face::new(3, Coordinate::new(3 * max_dim, 0, 0));
+    assert_eq!(
+        Overage::FaceEdge,
+        edge.adjust_overage_class_ii(res, false, true)
+    );
+
+    let mut over = Face::new(3, Coordinate::new(max_dim, 0, 1));
+    let expected = over.neighbor(KI).unwrap().face;
+    assert_eq!(
+        Overage::NewFace,
+        over.adjust_overage_class_ii(res, false, false)
+    );
+    assert_eq!(expected, over.face);
+}
+
+#[test]
+fn adjust_pent_vert_overage_stays_on_face() {
+    for bc in 0..NUM_BASE_CELLS as u64 {
+        let h = H3Index::init(3, bc, Direction::Center);
+        let Ok(pentagon) = FaceIJK::<Pentagon>::try_from(&h) else {
+            continue;
+        };
+
+        let (verts, adj_res) = pentagon.verts(3);
+        for mut vert in verts {
+            assert_ne!(Overage::NewFace, vert.adjust_pent_vert_overage(adj_res));
+        }
+    }
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{
+    latlng::LatLng,
+    polygon::{max_polygon_to_cells_size, polygon_to_cells, GeoLoop, GeoPolygon},
+    result::H3ErrorCode,
+    H3Index, M_PI,
+};
+
+fn geoloop(verts: &[(f64, f64)]) -> GeoLoop {
+    GeoLoop::new(
+        verts
+            .iter()
+            .map(|&(lat, lng)| LatLng::new(lat, lng))
+            .collect(),
+    )
+}
+
+fn sf() -> GeoLoop {
+    geoloop(&[
+        (0.659966917655, -2.1364398519396),
+        (0.6595011102219, -2.1359434279405),
+        (0.6583348114025, -2.1354884206045),
+        (0.6581220034068, -2.1382437718946),
+        (0.6594479998527, -2.1384597563896),
+        (0.6599990002976, -2.1376771158464),
+    ])
+}
+
+fn sf_hole() -> GeoLoop {
+    geoloop(&[
+        (0.6595072188743, -2.1371053983433),
+        (0.6591482046471, -2.1373141048153),
+        (0.6592295020837, -2.1365222838402),
+    ])
+}
+
+/// Asserts that the cells are exactly those reachable from them whose centers
+/// are in the polygon.
+fn assert_closed(polygon: &GeoPolygon, cells: &[H3Index]) {
+    let bboxes = polygon.bboxes();
+    let set: HashSet<_> = cells.iter().cloned().collect();
+    assert_eq!(set.len(), cells.len(), "duplicate cells");
+
+    for cell in cells {
+        assert!(polygon.contains(&bboxes, &cell.to_latlng().unwrap()));
+
+        for neighbor in cell.grid_disk(1).unwrap() {
+            assert_eq!(
+                set.contains(&neighbor),
+                polygon.contains(&bboxes, &neighbor.to_latlng().unwrap())
+            );
+        }
+    }
+}
+
+#[test]
+fn polygon_to_cells_sf() {
+    let polygon = GeoPolygon::new(sf(), vec![]);
+    let cells = polygon_to_cells(&polygon, 9).unwrap();
+    assert_eq!(1253, cells.len());
+    assert!(cells.len() <= max_polygon_to_cells_size(&polygon, 9).unwrap());
+    assert_closed(&polygon, &cells);
+}
+
+#[test]
+fn polygon_to_cells_sf_hole() {
+    let polygon = GeoPolygon::new(sf(), vec![sf_hole()]);
+    let cells = polygon_to_cells(&polygon, 9).unwrap();
+    assert_eq!(1214, cells.len());
+    assert_closed(&polygon, &cells);
+}
+
+#[test]
+fn polygon_to_cells_empty() {
+    let polygon = GeoPolygon::default();
+    assert_eq!(Ok(vec![]), polygon_to_cells(&polygon, 9));
+
+    let polygon = GeoPolygon::new(sf(), vec![]);
+    assert_eq!(Err(H3ErrorCode::ResDomain), polygon_to_cells(&polygon, 16));
+}
+
+#[test]
+fn polygon_to_cells_transmeridian() {
+    let prime_meridian = GeoPolygon::new(
+        geoloop(&[(0.01, 0.01), (0.01, -0.01), (-0.01, -0.01), (-0.01, 0.01)]),
+        vec![],
+    );
+    let transmeridian = GeoPolygon::new(
+        geoloop(&[
+            (0.01, -M_PI + 0.01),
+            (0.01, M_PI - 0.01),
+            (-0.01, M_PI - 0.01),
+            (-0.01, -M_PI + 0.01),
+        ]),
+        vec![],
+    );
+
+    let prime_cells = polygon_to_cells(&prime_meridian, 7).unwrap();
+    let trans_cells = polygon_to_cells(&transmeridian, 7).unwrap();
+    assert_eq!(4228, prime_cells.len());
+    // not exactly the prime meridian count because of slight differences in
+    // cell size and grid offset between the two cases
+    assert_eq!(4238, trans_cells.len());
+    assert_closed(&transmeridian, &trans_cells);
+
+    // a hole straddling the antimeridian
+    let with_hole = GeoPolygon::new(
+        transmeridian.geoloop.clone(),
+        vec![geoloop(&[
+            (0.005, -M_PI + 0.005),
+            (0.005, M_PI - 0.005),
+            (-0.005, M_PI - 0.005),
+            (-0.005, -M_PI + 0.005),
+        ])],
+    );
+    let hole_cells = polygon_to_cells(&with_hole, 7).unwrap();
+    assert!(hole_cells.len() < trans_cells.len());
+    assert_closed(&with_hole, &hole_cells);
+}
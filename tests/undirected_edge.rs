@@ -0,0 +1,133 @@
+mod common;
+
+use std::collections::HashSet;
+
+use h3_rs::{result::H3ErrorCode, H3Index};
+
+#[test]
+fn undirected_edges_of_all_cells() {
+    for res in 0..=2 {
+        let cells = common::all_cells(res);
+        let mut edges = HashSet::new();
+
+        for origin in &cells {
+            for directed in origin.origin_to_directed_edges().unwrap() {
+                let edge = directed.directed_edge_to_undirected().unwrap();
+                assert!(edge.is_valid_undirected_edge(), "{}", edge);
+                assert!(!edge.is_valid_directed_edge());
+                assert!(!edge.valid_cell());
+
+                // both directions name the same edge
+                let destination = directed.destination().unwrap();
+                let reverse = destination.cells_to_directed_edge(origin).unwrap();
+                assert_eq!(Ok(edge.clone()), reverse.directed_edge_to_undirected());
+                assert_eq!(
+                    Ok(edge.clone()),
+                    origin.cells_to_undirected_edge(&destination)
+                );
+                assert_eq!(
+                    Ok(edge.clone()),
+                    destination.cells_to_undirected_edge(origin)
+                );
+
+                let (low, high) = edge.undirected_edge_to_cells().unwrap();
+                assert!(low < high);
+                assert!(
+                    (&low, &high) == (origin, &destination)
+                        || (&low, &high) == (&destination, origin)
+                );
+
+                let (forward, backward) = edge.undirected_edge_to_directed_edges().unwrap();
+                assert_eq!(Ok(low.clone()), forward.origin());
+                assert_eq!(Ok(high.clone()), backward.origin());
+                assert_eq!(
+                    forward.directed_edge_to_boundary(),
+                    edge.undirected_edge_to_boundary()
+                );
+
+                edges.insert(edge);
+            }
+        }
+
+        // each cell has six edges less the pentagon deficit, and each edge is
+        // shared by two cells
+        assert_eq!(3 * cells.len() - 6, edges.len(), "res {}", res);
+    }
+}
+
+#[test]
+fn non_canonical_edges() {
+    let origin = common::cell("8928308280fffff");
+    for directed in origin.origin_to_directed_edges().unwrap() {
+        let edge = directed.directed_edge_to_undirected().unwrap();
+        let destination = directed.destination().unwrap();
+
+        // the same bits in edge mode are only canonical from the lower cell
+        let as_edge = H3Index::new((&directed & !(0b1111 << 59)) | (3 << 59));
+        assert_eq!(origin < destination, as_edge == edge);
+        assert_eq!(origin < destination, as_edge.is_valid_undirected_edge());
+
+        // and only the canonical form names an edge
+        if origin > destination {
+            assert_eq!(
+                Err(H3ErrorCode::UnDirEdgeInvalid),
+                as_edge.undirected_edge_to_cells()
+            );
+            assert_eq!(
+                Err(H3ErrorCode::UnDirEdgeInvalid),
+                as_edge.undirected_edge_to_directed_edges()
+            );
+            assert_eq!(
+                Err(H3ErrorCode::UnDirEdgeInvalid),
+                as_edge.undirected_edge_to_boundary()
+            );
+        }
+    }
+}
+
+#[test]
+fn invalid_edges() {
+    let origin = common::cell("8928308280fffff");
+    let far = &origin.grid_ring(2).unwrap()[0];
+    assert_eq!(
+        Err(H3ErrorCode::NotNeighbors),
+        origin.cells_to_undirected_edge(far)
+    );
+    assert_eq!(
+        Err(H3ErrorCode::NotNeighbors),
+        origin.cells_to_undirected_edge(&origin)
+    );
+
+    assert_eq!(
+        Err(H3ErrorCode::DirEdgeInvalid),
+        origin.directed_edge_to_undirected()
+    );
+    assert!(!origin.is_valid_undirected_edge());
+    assert_eq!(
+        Err(H3ErrorCode::UnDirEdgeInvalid),
+        origin.undirected_edge_to_cells()
+    );
+    assert_eq!(
+        Err(H3ErrorCode::UnDirEdgeInvalid),
+        origin.undirected_edge_to_boundary()
+    );
+
+    let directed = &origin.origin_to_directed_edges().unwrap()[0];
+    assert_eq!(
+        Err(H3ErrorCode::UnDirEdgeInvalid),
+        directed.undirected_edge_to_directed_edges()
+    );
+
+    // no edge in the center direction
+    let edge = directed.directed_edge_to_undirected().unwrap();
+    let center = H3Index::new(&edge & !(0b111 << 56));
+    assert!(!center.is_valid_undirected_edge());
+    assert_eq!(
+        Err(H3ErrorCode::UnDirEdgeInvalid),
+        center.undirected_edge_to_cells()
+    );
+    assert_eq!(
+        Err(H3ErrorCode::UnDirEdgeInvalid),
+        center.undirected_edge_to_boundary()
+    );
+}
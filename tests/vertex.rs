@@ -0,0 +1,101 @@
+mod common;
+
+use std::collections::HashMap;
+
+use h3_rs::{direction::Direction, result::H3ErrorCode, H3Index};
+
+#[test]
+fn vertexes_of_all_cells() {
+    for res in 0..=2 {
+        let cells = common::all_cells(res);
+        let mut owners: HashMap<H3Index, Vec<H3Index>> = HashMap::new();
+
+        for h3 in &cells {
+            let vertexes = h3.cell_to_vertexes().unwrap();
+            assert_eq!(if h3.is_pentagon() { 5 } else { 6 }, vertexes.len());
+
+            let boundary = h3.boundary().unwrap();
+            for vertex in vertexes {
+                assert!(vertex.is_valid_vertex(), "{}", vertex);
+                assert!(!vertex.valid_cell());
+
+                let latlng = vertex.vertex_to_latlng().unwrap();
+                assert!(
+                    common::contains_vert(boundary.verts(), &latlng),
+                    "{}",
+                    vertex
+                );
+
+                owners.entry(vertex).or_default().push(h3.clone());
+            }
+        }
+
+        // every vertex is shared by exactly three cells, which by Euler's
+        // formula gives two vertices per cell less the pentagon deficit
+        assert_eq!(2 * cells.len() - 4, owners.len(), "res {}", res);
+        // and is owned by the lowest of them
+        for (vertex, cells) in owners {
+            assert_eq!(3, cells.len(), "{}", vertex);
+            assert_eq!(cells.iter().min(), Some(&owner(&vertex)), "{}", vertex);
+        }
+    }
+}
+
+/// Returns the cell index of the owner of the vertex.
+fn owner(vertex: &H3Index) -> H3Index {
+    H3Index::new((vertex & !(0b111_1111 << 56)) | (1 << 59))
+}
+
+#[test]
+fn shared_vertex_of_neighbors() {
+    let origin = common::cell("823d6ffffffffff");
+    for neighbor in origin.grid_ring(1).unwrap() {
+        let shared: Vec<_> = origin
+            .cell_to_vertexes()
+            .unwrap()
+            .into_iter()
+            .filter(|v| neighbor.cell_to_vertexes().unwrap().contains(v))
+            .collect();
+        assert_eq!(2, shared.len(), "{}", neighbor);
+    }
+}
+
+#[test]
+fn invalid_vertexes() {
+    let hexagon = common::cell("823d6ffffffffff");
+    assert_eq!(Err(H3ErrorCode::Domain), hexagon.cell_to_vertex(6));
+    assert!(!hexagon.is_valid_vertex());
+    assert_eq!(Err(H3ErrorCode::Domain), hexagon.vertex_to_latlng());
+
+    let pentagon = H3Index::init(3, 14, Direction::Center);
+    assert_eq!(Err(H3ErrorCode::Domain), pentagon.cell_to_vertex(5));
+    assert!(pentagon.cell_to_vertex(4).unwrap().is_valid_vertex());
+
+    // a vertex number out of range, and a vertex not named by its owner
+    let vertex = hexagon.cell_to_vertex(0).unwrap();
+    for n in 6..8 {
+        let out_of_range = H3Index::new((&vertex & !(0b111 << 56)) | (n << 56));
+        assert!(!out_of_range.is_valid_vertex());
+        assert_eq!(Err(H3ErrorCode::Domain), out_of_range.vertex_to_latlng());
+    }
+
+    // pentagons have no sixth vertex
+    let out_of_range = H3Index::new((&pentagon & !(0b111_1111 << 56)) | (4 << 59) | (5 << 56));
+    assert!(!out_of_range.is_valid_vertex());
+    assert_eq!(Err(H3ErrorCode::Domain), out_of_range.vertex_to_latlng());
+
+    let cells = hexagon.grid_disk(1).unwrap();
+    let non_owner = cells.iter().max().unwrap();
+    let vertexes = non_owner.cell_to_vertexes().unwrap();
+    let non_canonical = (0..6)
+        .map(|n| H3Index::new((non_owner & !(0b111_1111 << 56)) | (4 << 59) | (n << 56)))
+        .find(|v| !vertexes.contains(v))
+        .unwrap();
+    assert!(!non_canonical.is_valid_vertex());
+
+    // only cells have vertexes
+    for invalid in [&vertex, &H3Index::new(0)] {
+        assert_eq!(Err(H3ErrorCode::CellInvalid), invalid.cell_to_vertex(0));
+        assert_eq!(Err(H3ErrorCode::CellInvalid), invalid.cell_to_vertexes());
+    }
+}